@@ -7,7 +7,7 @@ impl Cmd {
         App::new("alias").about("alias gen")
     }
 
-    pub fn gen(subcommands: &Vec<&str>) {
+    pub fn gen(subcommands: &[&str]) {
         // let subcommands = vec!["df", "docker", "dig", "du", "env", "fdisk", "free", "ping", "top"];
         let mut alias = String::new();
         for cmd_str in subcommands.iter() {
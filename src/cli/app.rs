@@ -0,0 +1,348 @@
+use std::{
+    io,
+    sync::{atomic::Ordering, Arc},
+};
+
+use clap::{App, AppSettings, Arg, Clap};
+
+use crate::cli::{
+    alias,
+    cli::{pre_exec, Context, Opts},
+    completion::Completion,
+    config, log, palette,
+    registry::{self, Mapping, COMMANDS},
+    ualias, universal,
+};
+
+use clap_generate::{
+    generate,
+    generators::{Bash, Elvish, Fish, PowerShell, Zsh},
+};
+use std::io::Write;
+use termcolor::{BufferWriter, ColorChoice};
+
+fn build_app(mappings: &[Mapping]) -> App<'static> {
+    base_app()
+        .subcommands(vec![
+            alias::Cmd::new(),
+            ualias::Cmd::new(),
+            Completion::new(),
+            palette::Cmd::new(),
+            log::Cmd::new(),
+        ])
+        .subcommands(COMMANDS.iter().map(|command| (command.app)()))
+        .subcommands(mappings.iter().filter_map(Mapping::app))
+}
+
+// The options of ufc, without its subcommands
+fn base_app() -> App<'static> {
+    App::new("ufc")
+        .version("v0.8.5")
+        .about("Ultimate Friendly Colorizer")
+        .author("The UFC Team <https://github.com/ufc-cli/ufc>")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .setting(AppSettings::AllowExternalSubcommands)
+        .global_setting(AppSettings::ColorAlways)
+        .global_setting(AppSettings::ColoredHelp)
+        .global_setting(AppSettings::DisableVersionForSubcommands)
+        .global_setting(AppSettings::DisableHelpSubcommand)
+        .global_setting(AppSettings::DisableHelpFlag)
+        .args(&[
+            Arg::new("watch")
+                .long("watch")
+                .short('w')
+                .default_value("0")
+                .about(r#"Optional watch mode, Duration of waiting for executing subcommand periodically. Values can be "1.5h", "2m", "5s", "5" or "1.5h2m5s", set to "0" to disable it."#),
+            Arg::new("time")
+                .long("time")
+                .short('t')
+                .about("Optional time mode, timing statistics when the subprogram exits"),
+            Arg::new("boost")
+                .long("boost")
+                .short('b')
+                .about("Optional boost mode, make mass stdout/stderr print faster by using BufferedStandardStream"),
+            Arg::new("nocolor")
+                .long("nocolor")
+                .short('n')
+                .about("Disable colorizer, alias of --color=never"),
+            Arg::new("color")
+                .long("color")
+                .takes_value(true)
+                .possible_values(&["auto", "always", "never"])
+                .default_value("auto")
+                .about(r#"When to colorize the output. "auto" colorizes only when writing to a terminal and honors NO_COLOR, CLICOLOR_FORCE and TERM=dumb"#),
+            Arg::new("output")
+                .long("output")
+                .takes_value(true)
+                .possible_values(&["terminal", "html", "json"])
+                .default_value("terminal")
+                .about(r#"Output format, "html" renders the colorized output as a standalone html page, "json" prints each line with its colored spans as NDJSON"#),
+            Arg::new("explain")
+                .long("explain")
+                .about("Print which palettes are tried on each line, what they match and the resulting colors to stderr, same as UFC_TRACE=1"),
+            Arg::new("profile")
+                .long("profile")
+                .takes_value(true)
+                .about(r#"Colorize any command with the palettes of a profile, e.g. --profile df or --profile "docker ps", see `ufc palette list`"#),
+            Arg::new("universal")
+                .long("universal")
+                .short('u')
+                .about("Universal subcommand, this option will try to colorize unsupported subcommands"),
+            Arg::new("grep")
+                .long("grep")
+                .takes_value(true)
+                .about("Only print the lines matching a regex, the matches are highlighted on top of the palette colors"),
+            Arg::new("min-level")
+                .long("min-level")
+                .takes_value(true)
+                .about("Only print the lines of a level at least as severe, e.g. warn, the lines without a level belong to the one above"),
+            Arg::new("context")
+                .long("context")
+                .short('C')
+                .takes_value(true)
+                .default_value("0")
+                .about("Lines printed around each line kept by --grep or --min-level"),
+            Arg::new("highlight")
+                .long("highlight")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .number_of_values(1)
+                .about("Color the matches of a regex for this run, e.g. --highlight timeout=BRed --highlight myhost, repeatable, distinct background colors are picked when no style is given"),
+            Arg::new("warn")
+                .long("warn")
+                .takes_value(true)
+                .about("Warning level of the percentages, sizes and latencies, e.g. --warn 80 (percent or ms), --warn 2G or --warn 150ms"),
+            Arg::new("crit")
+                .long("crit")
+                .takes_value(true)
+                .about("Critical level of the percentages, sizes and latencies, e.g. --crit 95"),
+        ])
+}
+
+// The wrapped command line, e.g. ["docker", "ps", "-a"]. Without its subcommands every command
+// is an external one to clap, which hands back the args which follow it untouched instead of
+// parsing them with the `App` of the command
+fn subcommand_argv(args: &[String]) -> Vec<String> {
+    let matches = match base_app().try_get_matches_from(args) {
+        Ok(matches) => matches,
+        Err(_) => return vec![],
+    };
+    match matches.subcommand() {
+        Some((name, sub)) => std::iter::once(name)
+            .chain(sub.values_of("").into_iter().flatten())
+            .map(String::from)
+            .collect(),
+        None => vec![],
+    }
+}
+
+// Whether the wrapped command line runs another command, e.g. "env LANG=C df -h" or "sudo df"
+fn is_wrapper(argv: &[String]) -> bool {
+    let effective = registry::effective_argv(argv);
+    !effective.is_empty() && effective.len() < argv.len()
+}
+
+// Parse the command line of ufc and run it, the exit code of the binary is returned
+pub fn run() -> i32 {
+    let mappings = config::user_mappings();
+    let args: Vec<String> = std::env::args().collect();
+    let argv = subcommand_argv(&args);
+    let is_wrapper = is_wrapper(&argv);
+    // Left to `detect` without the subcommands of ufc, e.g. `ufc env LANG=C df -h` isn't
+    // parsed by the `env` subcommand
+    let app_matches = match is_wrapper {
+        true => base_app().get_matches_from(&args),
+        false => build_app(&mappings).get_matches_from(&args),
+    };
+    let mut clap_args = Opts::parse_from(&args);
+    if std::env::var("UFC_TRACE").map_or(false, |v| !v.is_empty() && v != "0") {
+        clap_args.explain = true;
+    }
+    let ctx = Context::new(clap_args, argv);
+    if let Some(Err(e)) = ctx.grep.as_ref().map(|grep| grep.compile()) {
+        eprintln!("ufc: --grep: {}", e);
+        return 2;
+    }
+    for palette in ctx.highlights {
+        if let Err(e) = palette.regexp.compile() {
+            eprintln!("ufc: {}: {}", palette.name, e);
+            return 2;
+        }
+    }
+    // Ctrl-c only raises a flag, the subcommand is given a moment to end by itself and the
+    // end of the output (--time, the html footer) is still printed
+    let interrupted = Arc::clone(&ctx.interrupted);
+    if let Err(e) = ctrlc::set_handler(move || interrupted.store(true, Ordering::SeqCst)) {
+        eprintln!("ufc: {}", e);
+    }
+
+    let exit_code = match app_matches.subcommand() {
+        Some(("completion", args)) => {
+            match args.subcommand_name() {
+                Some("bash") => {
+                    generate::<Bash, _>(&mut build_app(&mappings), "ufc", &mut io::stdout());
+                }
+                Some("zsh") => {
+                    let stdout = BufferWriter::stdout(ColorChoice::Never);
+                    let mut buf = stdout.buffer();
+                    writeln!(buf, "compdef _ufc ufc").unwrap();
+
+                    generate::<Zsh, _>(&mut build_app(&mappings), "ufc", &mut buf);
+                    let mut completion = String::from_utf8(buf.as_slice().to_vec()).unwrap();
+                    completion = completion.strip_suffix(r#"_ufc "$@""#).unwrap().to_string();
+                    println!("{}", completion);
+                }
+                Some("fish") => {
+                    let stdout = BufferWriter::stdout(ColorChoice::Never);
+                    let mut buf = stdout.buffer();
+                    generate::<Fish, _>(&mut build_app(&mappings), "ufc", &mut buf);
+                    let mut completion = String::from_utf8(buf.as_slice().to_vec()).unwrap();
+                    completion = completion.replace("\n", ";");
+                    println!("{}", completion);
+                }
+                Some("powershell") => {
+                    generate::<PowerShell, _>(&mut build_app(&mappings), "ufc", &mut io::stdout());
+                }
+                Some("elvish") => {
+                    generate::<Elvish, _>(&mut build_app(&mappings), "ufc", &mut io::stdout());
+                }
+                _ => {
+                    println!("Unsupported completion")
+                }
+            }
+            0
+        }
+        Some(("alias", _args)) => {
+            alias::Cmd::gen(&registry::command_names(&mappings));
+            0
+        }
+        Some(("ualias", _args)) => {
+            ualias::Cmd::gen(&registry::command_names(&mappings));
+            0
+        }
+        Some(("palette", args)) => palette::Cmd::parse(ctx, args),
+        Some(("log", args)) => log::Cmd::parse(ctx, args),
+        Some(_) if ctx.clap_args.profile.is_some() => {
+            let name = ctx.clap_args.profile.clone().unwrap_or_default();
+            match registry::find(&name) {
+                Some(profile) => profile.run(ctx),
+                None => {
+                    println!(
+                        "Unsupported profile \"{}\", try `ufc palette list` to get all profiles",
+                        name
+                    );
+                    0
+                }
+            }
+        }
+        Some((name, args)) => match registry::command(name).filter(|_| !is_wrapper) {
+            Some(command) => (command.parse)(ctx, args),
+            None => match registry::detect(&ctx.argv, &mappings) {
+                // e.g. "sudo df -h", "/usr/sbin/fdisk -l" or a user-defined "podman ps"
+                Some(profile) => profile.run(ctx),
+                None if ctx.clap_args.universal => universal::Cmd::parse(ctx, args),
+                // Only the --highlight patterns, e.g. `ufc --highlight error make`
+                None if !ctx.highlights.is_empty() => pre_exec(ctx, &[]),
+                None => {
+                    println!("Unsupported subcommand, please use -u or --universal to enable universal mode.\nThis option will try to colorize unsupported subcommands");
+                    0
+                }
+            },
+        },
+        None => {
+            println!("Please try -h or --help to get the full usages");
+            0
+        }
+    };
+    exit_code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The wrapped command line of `cmdline`, checked against how clap splits it
+    fn argv(cmdline: &str) -> Vec<String> {
+        let args: Vec<String> = cmdline.split(' ').map(String::from).collect();
+        let argv = subcommand_argv(&args);
+
+        let matches = build_app(&[]).try_get_matches_from(&args).unwrap();
+        assert_eq!(matches.subcommand_name(), argv.first().map(String::as_str));
+        if let Some(values) = matches.subcommand().and_then(|(_, sub)| sub.values_of("")) {
+            assert_eq!(values.collect::<Vec<_>>(), argv[1..]);
+        }
+        argv
+    }
+
+    #[test]
+    fn subcommand_only() {
+        assert_eq!(argv("ufc df -h"), ["df", "-h"]);
+        assert_eq!(argv("ufc docker ps -a"), ["docker", "ps", "-a"]);
+    }
+
+    #[test]
+    fn options_with_values() {
+        assert_eq!(argv("ufc -w 5 df -h"), ["df", "-h"]);
+        assert_eq!(argv("ufc -w5 df"), ["df"]);
+        assert_eq!(argv("ufc --watch=5 -t df"), ["df"]);
+        assert_eq!(argv("ufc -tw 5 df"), ["df"]);
+        assert_eq!(argv("ufc --color never --output json id"), ["id"]);
+        assert_eq!(argv("ufc --grep fail -C 2 --min-level warn df"), ["df"]);
+        assert_eq!(argv("ufc --warn 80 --crit 95% df -h"), ["df", "-h"]);
+        assert_eq!(
+            argv("ufc --highlight timeout=BRed --highlight host make"),
+            ["make"]
+        );
+    }
+
+    #[test]
+    fn option_value_equals_command() {
+        assert_eq!(
+            argv("ufc -u --output json json -x json"),
+            ["json", "-x", "json"]
+        );
+        assert_eq!(argv("ufc -u --color always always"), ["always"]);
+        // A built-in command as the value of an option, not the command itself
+        assert_eq!(argv("ufc -w top top"), ["top"]);
+        // Left to the `App` of top, which has no arguments and rejects the second "top"
+        let args: Vec<String> = "ufc -w 1 top top".split(' ').map(String::from).collect();
+        assert_eq!(subcommand_argv(&args), ["top", "top"]);
+        assert!(build_app(&[]).try_get_matches_from(&args).is_err());
+    }
+
+    #[test]
+    fn ufc_options_after_command_belong_to_it() {
+        assert_eq!(argv("ufc -u ls -t -w 5"), ["ls", "-t", "-w", "5"]);
+        assert_eq!(argv("ufc df -t ext4"), ["df", "-t", "ext4"]);
+    }
+
+    #[test]
+    fn env_wrapper() {
+        for cmdline in [
+            "ufc env LANG=C df -h",
+            "ufc env -i -- TZ=UTC df",
+            "ufc --profile df env LANG=C df",
+        ]
+        .iter()
+        {
+            let args: Vec<String> = cmdline.split(' ').map(String::from).collect();
+            let argv = subcommand_argv(&args);
+            assert!(is_wrapper(&argv), "{}", cmdline);
+            assert!(base_app().try_get_matches_from(&args).is_ok());
+            let profile = registry::detect(&argv, &[]).map(|profile| profile.name);
+            assert_eq!(profile, Some("df"));
+        }
+        // The env subcommand
+        assert!(!is_wrapper(&argv("ufc env")));
+        assert!(!is_wrapper(&argv("ufc env -i")));
+        assert!(!is_wrapper(&argv("ufc env -u HOME")));
+        assert!(!is_wrapper(&argv("ufc docker ps")));
+    }
+
+    #[test]
+    fn double_dash() {
+        assert_eq!(argv("ufc -t -- df -h"), ["df", "-h"]);
+        assert_eq!(argv("ufc -u echo -- -x"), ["echo", "--", "-x"]);
+        assert_eq!(argv("ufc -u grep -e -- --"), ["grep", "-e", "--", "--"]);
+    }
+}
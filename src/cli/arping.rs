@@ -0,0 +1,34 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, AppSettings, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("arping")
+            .args(&[
+                Arg::new("destination").about("Destination IP address").required(true),
+                Arg::new("A").short('A').about("The same as -U, but ARP REPLY packets used instead of ARP REQUEST."),
+                Arg::new("b").short('b').about("Send only MAC level broadcasts."),
+                Arg::new("count").short('c').takes_value(true).about("Stop after sending count ARP REQUEST packets."),
+                Arg::new("D").short('D').about("Duplicate address detection mode (DAD)."),
+                Arg::new("f").short('f').about("Finish after the first reply confirming that target is alive."),
+                Arg::new("interface").short('I').takes_value(true).about("Name of network device where to send ARP REQUEST packets."),
+                Arg::new("q").short('q').about("Quiet output."),
+                Arg::new("source").short('s').takes_value(true).about("IP source address to use in ARP packets."),
+                Arg::new("U").short('U').about("Unsolicited ARP mode to update neighbours' ARP caches."),
+                Arg::new("V").short('V').about("Print version of the program and exit."),
+                Arg::new("deadline").short('w').takes_value(true).about("Specify a timeout, in seconds, before arping exits regardless of how many packets have been sent or received."),
+                Arg::new("timeout").short('W').takes_value(true).about("Time to wait between pings."),
+            ])
+            .setting(AppSettings::ArgRequiredElseHelp)
+            .about("arping")
+    }
+
+    pub fn parse(ctx: Context, _app: &ArgMatches) -> i32 {
+        pre_exec(ctx, registry::palettes("arping"))
+    }
+}
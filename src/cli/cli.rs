@@ -1,46 +1,137 @@
 use std::{
     env,
+    ffi::OsString,
+    fmt,
     io::{BufRead, BufReader, Write},
     num::ParseFloatError,
-    process::{
-        Child, Command, Stdio, {self},
-    },
+    process::{Command, Stdio},
     str::FromStr,
-    sync::{Arc, RwLock},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
     thread,
-    time::{Duration, SystemTime},
+    time::{Duration, Instant, SystemTime},
 };
 
 use atty::Stream;
 use clap::{AppSettings, Clap};
 use fancy_regex::Regex;
-use lazy_static::lazy_static;
-use termcolor::{BufferWriter, BufferedStandardStream, Color, ColorChoice, ColorSpec, WriteColor};
-
-lazy_static! {
-    // Global SETTINGS
-    pub static ref SETTINGS: RwLock<Settings> = RwLock::new(Settings {
-        clap_args: Opts{
-            watch: 0.0,
-            time: false,
-            boost: false,
-            nocolor: false,
-            universal: false,
-        },
-        subcommand_name: String::new(),
-        subcommand_start: SystemTime::now(),
-        palettes: vec![],
-        is_tty: atty::is(Stream::Stdout),
-    });
-}
+use termcolor::{BufferWriter, BufferedStandardStream, ColorChoice, WriteColor};
+use ufc::{get_color, Colorizer, LazyRegex, Palette, Span, Style, Threshold, Thresholds};
 
+use super::{
+    config,
+    filter::{Filter, Level},
+    html, json,
+};
+
+// Everything a run of ufc needs, built once in `main` and handed down to the subcommand,
+// shared read-only with the output threads
 #[derive(Debug)]
-pub struct Settings {
+pub struct Context {
     pub clap_args: Opts,
+    // Exact command line of the wrapped command, e.g. ["docker", "ps", "-a"]
+    pub argv: Vec<String>,
     pub subcommand_name: String,
     pub subcommand_start: SystemTime,
-    pub palettes: Vec<Palette<'static>>,
+    pub colorizer: Colorizer<'static>,
     pub is_tty: bool,
+    // Rewrites the stdout lines before they are colored, e.g. `journalctl`
+    pub rewriter: Option<Mutex<Box<dyn Rewrite>>>,
+    // Set by the ctrl-c handler of `main`, so the end of an interrupted output can be told from a
+    // finished one, e.g. the summary of `ping`
+    pub interrupted: Arc<AtomicBool>,
+    // --grep, its matches are also highlighted
+    pub grep: Option<LazyRegex>,
+    // --highlight, tried before the palettes of the subcommand
+    pub highlights: &'static [Palette<'static>],
+    // --warn and --crit, the levels of the gauge rules
+    pub thresholds: Thresholds,
+}
+
+// Background colors given in turn to the --highlight patterns without a style
+const HIGHLIGHT_COLORS: [&Style; 6] = [
+    &Style::BlackOnYellow,
+    &Style::BlackOnGreen,
+    &Style::BlackOnCyan,
+    &Style::BlackOnMagenta,
+    &Style::BlackOnRed,
+    &Style::BlackOnBlue,
+];
+
+// The palettes of `--highlight <regex>[=<style>]`, e.g. "timeout=BRed", a pattern not ending with
+// a known color is a regex on its own ("level=error") and takes the next of `HIGHLIGHT_COLORS`
+pub fn highlight_palettes(patterns: &[String]) -> Vec<Palette<'static>> {
+    let mut auto = HIGHLIGHT_COLORS.iter().cycle();
+    patterns
+        .iter()
+        .map(|pattern| {
+            let (regexp, color) = match pattern
+                .rsplit_once('=')
+                .and_then(|(regexp, name)| Some((regexp, Style::from_name(name)?)))
+            {
+                Some((regexp, color)) if !regexp.is_empty() => (regexp, color),
+                _ => (
+                    pattern.as_str(),
+                    *auto.next().unwrap_or(&&Style::BlackOnYellow),
+                ),
+            };
+            Palette {
+                name: Box::leak(format!("--highlight {}", pattern).into_boxed_str()),
+                regexp: LazyRegex::from_string(regexp.to_string()),
+                colors: vec![color],
+            }
+        })
+        .collect()
+}
+
+// A line to print, `style` colors the parts no palette colors and `level` is its severity
+// when the subcommand tells it, otherwise --min-level looks for it in the text
+#[derive(Debug)]
+pub struct Line {
+    pub text: String,
+    pub style: &'static Style,
+    pub level: Option<Level>,
+}
+
+impl Line {
+    pub const fn new(text: String) -> Self {
+        Self {
+            text,
+            style: &Style::Default,
+            level: None,
+        }
+    }
+}
+
+// Turns the stdout of a subcommand into the lines to colorize, e.g. the entries of
+// `journalctl -o json` into short lines
+pub trait Rewrite: Send + fmt::Debug {
+    fn rewrite(&mut self, ln: String) -> Vec<Line>;
+
+    // Called once the output ends, `interrupted` by ctrl-c or not, for what's left to print
+    fn finish(&mut self, _interrupted: bool) -> Vec<Line> {
+        vec![]
+    }
+}
+
+impl Context {
+    pub fn new(clap_args: Opts, argv: Vec<String>) -> Self {
+        Self {
+            subcommand_name: argv.first().cloned().unwrap_or_default(),
+            argv,
+            subcommand_start: SystemTime::now(),
+            colorizer: Colorizer::new(vec![]),
+            is_tty: atty::is(Stream::Stdout),
+            rewriter: None,
+            interrupted: Arc::new(AtomicBool::new(false)),
+            grep: clap_args.grep.clone().map(LazyRegex::from_string),
+            highlights: Box::leak(highlight_palettes(&clap_args.highlight).into_boxed_slice()),
+            thresholds: Thresholds::new(clap_args.warn, clap_args.crit),
+            clap_args,
+        }
+    }
 }
 
 #[derive(Debug, Clap)]
@@ -71,8 +162,53 @@ pub struct Opts {
     #[clap(short = 'n', long = "nocolor")]
     pub nocolor: bool,
 
+    #[clap(
+        long = "color",
+        parse(try_from_str),
+        possible_values = &["auto", "always", "never"],
+        default_value = "auto",
+    )]
+    pub color: ColorMode,
+
+    #[clap(
+        long = "output",
+        parse(try_from_str),
+        possible_values = &["terminal", "html", "json"],
+        default_value = "terminal",
+    )]
+    pub output: OutputFormat,
+
+    #[clap(long = "explain")]
+    pub explain: bool,
+
+    #[clap(long = "profile")]
+    pub profile: Option<String>,
+
     #[clap(short = 'u', long = "universal")]
     pub universal: bool,
+
+    #[clap(long = "grep")]
+    pub grep: Option<String>,
+
+    #[clap(long = "min-level", parse(try_from_str))]
+    pub min_level: Option<Level>,
+
+    #[clap(long = "context", short = 'C', default_value = "0")]
+    pub context: usize,
+
+    #[clap(
+        long = "highlight",
+        multiple_occurrences = true,
+        multiple_values = false,
+        number_of_values = 1
+    )]
+    pub highlight: Vec<String>,
+
+    #[clap(long = "warn", parse(try_from_str))]
+    pub warn: Option<Threshold>,
+
+    #[clap(long = "crit", parse(try_from_str))]
+    pub crit: Option<Threshold>,
 }
 
 fn parse_watch_duration(src: &str) -> Result<f64, ParseFloatError> {
@@ -97,92 +233,77 @@ fn parse_watch_duration(src: &str) -> Result<f64, ParseFloatError> {
     }
 }
 
-#[derive(Debug)]
-struct ColorString<'a> {
-    text: String,
-    color: &'a Colors,
+#[derive(Debug, PartialEq)]
+pub enum ColorMode {
+    Auto,
+    Always,
+    Never,
 }
 
-#[derive(Debug)]
-pub struct Palette<'a> {
-    pub regexp: Regex,
-    pub colors: Vec<&'a Colors>,
+impl FromStr for ColorMode {
+    type Err = String;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        match src {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(format!("invalid color mode \"{}\"", src)),
+        }
+    }
 }
 
-#[allow(dead_code)]
-#[derive(Debug, PartialEq)]
-pub enum Colors {
-    UnChanged,
-    Default,
-    Black,
-    Blue,
-    Green,
-    Red,
-    Cyan,
-    Magenta,
-    Yellow,
-    White,
-    // BOld
-    BDefault,
-    BBlack,
-    BBlue,
-    BGreen,
-    BRed,
-    BCyan,
-    BMagenta,
-    BYellow,
-    BWhite,
-    // Dark
-    DDefault,
-    DBlack,
-    DBlue,
-    DGreen,
-    DRed,
-    DCyan,
-    DMagenta,
-    DYellow,
-    DWhite,
-    // Backgroud Color
-    OnBlack,
-    OnBlue,
-    OnGreen,
-    OnRed,
-    OnCyan,
-    OnMagenta,
-    OnYellow,
-    OnWhite,
-    BlackOnBlack,
-    BlackOnBlue,
-    BlackOnGreen,
-    BlackOnRed,
-    BlackOnCyan,
-    BlackOnMagenta,
-    BlackOnYellow,
-    BlackOnWhite,
-    // Undoerline
-    UDefault,
-    UBlack,
-    UBlue,
-    UGreen,
-    URed,
-    UCyan,
-    UMagenta,
-    UYellow,
-    UWhite,
-    // Underline Bold
-    UBDefault,
-    UBBlack,
-    UBBlue,
-    UBGreen,
-    UBRed,
-    UBCyan,
-    UBMagenta,
-    UBYellow,
-    UBWhite,
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Terminal,
+    Html,
+    Json,
 }
 
-fn clear_screen() {
-    if !SETTINGS.read().unwrap().is_tty && cfg!(windows) {
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        match src {
+            "terminal" => Ok(Self::Terminal),
+            "html" => Ok(Self::Html),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("invalid output format \"{}\"", src)),
+        }
+    }
+}
+
+// Whether the output of a stream should be colorized, `--nocolor` is an alias of `--color=never`
+// https://no-color.org and https://bixense.com/clicolors
+pub fn is_colored(clap_args: &Opts, stream: Stream) -> bool {
+    color_decision(clap_args, |name| env::var_os(name), atty::is(stream))
+}
+
+// The decision of `is_colored` from the variables of `env` and whether the stream is a tty
+fn color_decision(clap_args: &Opts, env: impl Fn(&str) -> Option<OsString>, is_tty: bool) -> bool {
+    if clap_args.nocolor {
+        return false;
+    }
+    match clap_args.color {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => {
+            if env("NO_COLOR").map_or(false, |v| !v.is_empty()) {
+                return false;
+            }
+            if env("CLICOLOR_FORCE").map_or(false, |v| !v.is_empty() && v != "0") {
+                return true;
+            }
+            if env("TERM").map_or(false, |v| v == "dumb") {
+                return false;
+            }
+            is_tty
+        }
+    }
+}
+
+fn clear_screen(ctx: &Context) {
+    if !ctx.is_tty && cfg!(windows) {
         let _ = Command::new("cmd.exe").args(&["/c", "cls"]).status();
     } else {
         // https://stackoverflow.com/a/34837038
@@ -190,123 +311,252 @@ fn clear_screen() {
     }
 }
 
-fn process_exit(exit_code: i32) {
-    if !SETTINGS.read().unwrap().clap_args.time {
-        process::exit(exit_code);
-    }
-    match SETTINGS.read().unwrap().subcommand_start.elapsed() {
-        Ok(elapsed) => {
-            println!("\nThe subcommand took {:?} to finish", elapsed);
-        }
-        Err(e) => {
-            println!("Error: {:?}", e);
+// What's printed once the subcommand is done, finished or interrupted
+fn print_end(ctx: &Context) {
+    if ctx.clap_args.time {
+        match ctx.subcommand_start.elapsed() {
+            Ok(elapsed) => {
+                println!("\nThe subcommand took {:?} to finish", elapsed);
+            }
+            Err(e) => {
+                println!("Error: {:?}", e);
+            }
         }
     }
-    process::exit(exit_code);
+    if ctx.clap_args.output == OutputFormat::Html {
+        println!("{}", html::footer());
+    }
 }
 
-pub fn pre_exec(palettes: Vec<Palette<'static>>) {
-    SETTINGS.write().unwrap().palettes = palettes;
-    let ctrlc_hit = Arc::new(RwLock::new(false));
-    let setting = SETTINGS.read().unwrap();
-    let arg_start = env::args()
-        .position(|cmd| cmd.eq(&setting.subcommand_name))
-        .unwrap();
-
-    let mut subcommand_proc = Arc::new(RwLock::new(Command::new("true").spawn().expect("")));
-    let child_clone = Arc::clone(&subcommand_proc);
-
-    // Clone the process to the ctrlc thread (to be killed)
-    let ctrlc_hit_clone = Arc::clone(&ctrlc_hit);
-
-    ctrlc::set_handler(move || {
-        // println!("ctrlc hit!");
-        match child_clone.write().unwrap().kill() {
-            // Ignore kill() error, because the program exits anyway
-            Err(_) => {}
-            Ok(_) => {}
-        }
-        // println!("ctrlc hit end!");
-        *ctrlc_hit_clone.write().unwrap() = true;
-        // If the program does not stop after 100ms, e.g blocked by thread::sleep, then force quit
-        // This is required because some program will output its last words before exiting
-        thread::sleep(Duration::from_millis(100));
-        process_exit(0);
-    })
-    .unwrap();
+// Run the command line of `ctx` colored by `palettes` (every --watch period until ctrl-c)
+// and return its exit code
+pub fn pre_exec(mut ctx: Context, palettes: &'static [Palette<'static>]) -> i32 {
+    // --highlight and the user-defined palettes go first so they take precedence over the
+    // built-in ones
+    let user_palettes = config::user_palettes(&ctx.subcommand_name);
+    ctx.colorizer = Colorizer::new(
+        ctx.highlights
+            .iter()
+            .chain(user_palettes.iter())
+            .chain(palettes.iter())
+            .collect(),
+    )
+    .with_thresholds(ctx.thresholds);
+    let ctx = Arc::new(ctx);
+
+    if ctx.clap_args.output == OutputFormat::Html {
+        print!("{}", html::header(&ctx.argv.join(" ")));
+    }
 
     let mut exit_code = 0;
-    if setting.clap_args.watch != 0.0 {
-        while !*ctrlc_hit.read().unwrap() {
-            clear_screen();
-            exit_code = exec(arg_start, &mut subcommand_proc);
-            thread::sleep(Duration::from_secs_f64(setting.clap_args.watch));
+    if ctx.clap_args.watch != 0.0 {
+        while !ctx.interrupted.load(Ordering::SeqCst) {
+            if ctx.clap_args.output == OutputFormat::Terminal {
+                clear_screen(&ctx);
+            }
+            exit_code = exec(&ctx);
+            let next = Instant::now() + Duration::from_secs_f64(ctx.clap_args.watch);
+            while Instant::now() < next && !ctx.interrupted.load(Ordering::SeqCst) {
+                thread::sleep(POLL_INTERVAL);
+            }
         }
     } else {
-        exit_code = exec(arg_start, &mut subcommand_proc);
+        exit_code = exec(&ctx);
+    }
+    print_end(&ctx);
+    exit_code
+}
+
+// Colorize lines which don't come from a subcommand, e.g. a sample file of `ufc palette test`
+pub fn print_lines(
+    mut ctx: Context,
+    palettes: Vec<&'static Palette<'static>>,
+    reader: impl BufRead,
+) {
+    ctx.colorizer = Colorizer::new(ctx.highlights.iter().chain(palettes).collect())
+        .with_thresholds(ctx.thresholds);
+    let output = ctx.clap_args.output;
+    let is_stdout_colored = is_colored(&ctx.clap_args, Stream::Stdout);
+    let stdout_bufwtr = BufferWriter::stdout(ColorChoice::Always);
+
+    if output == OutputFormat::Html {
+        print!("{}", html::header("ufc"));
+    }
+    let mut filter = Filter::new(&ctx);
+    for line in reader.lines() {
+        let ln = match line {
+            Ok(ln) => ln,
+            Err(e) => {
+                eprintln!("ufc: {}", e);
+                break;
+            }
+        };
+        for line in filter.push(Line::new(ln)) {
+            print_line(
+                &ctx,
+                &stdout_bufwtr,
+                is_stdout_colored,
+                line.text,
+                line.style,
+            );
+        }
+    }
+    if output == OutputFormat::Html {
+        println!("{}", html::footer());
+    }
+}
+
+// Print a line of `print_lines` or `ufc log` to stdout, the parts no palette colors get `style`
+pub fn print_line(
+    ctx: &Context,
+    bufwtr: &BufferWriter,
+    is_colored: bool,
+    ln: String,
+    style: &'static Style,
+) {
+    match ctx.clap_args.output {
+        OutputFormat::Html => color_html(ctx, ln, style),
+        OutputFormat::Json => color_json(ctx, "stdout", ln, style),
+        OutputFormat::Terminal if is_colored => color_std(ctx, bufwtr, ln, style),
+        OutputFormat::Terminal => println!("{}", ln),
     }
-    process_exit(exit_code);
 }
 
-fn exec(arg_start: usize, subcommand_proc: &mut Arc<RwLock<Child>>) -> i32 {
-    // let palettes = SETTINGS.read().unwrap().palettes;
-    let args: Vec<String> = env::args().collect();
-
-    *subcommand_proc = Arc::new(RwLock::new(
-        Command::new(args[arg_start].as_str())
-            .args(&args[arg_start + 1..])
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .unwrap(),
-    ));
-
-    let stdout = BufReader::new(subcommand_proc.write().unwrap().stdout.take().unwrap());
-    let stderr = BufReader::new(subcommand_proc.write().unwrap().stderr.take().unwrap());
+// How often the subcommand and the ctrl-c flag are checked
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+// Time given to an interrupted subcommand to print its last words before it's killed,
+// e.g. the statistics of ping
+const KILL_DELAY: Duration = Duration::from_millis(100);
+
+fn exec(ctx: &Arc<Context>) -> i32 {
+    let mut child = match Command::new(&ctx.argv[0])
+        .args(&ctx.argv[1..])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            eprintln!("ufc: {}: {}", ctx.argv[0], e);
+            return 127;
+        }
+    };
+
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    let stderr = BufReader::new(child.stderr.take().unwrap());
     let stdout_bufwtr = BufferWriter::stdout(ColorChoice::Always);
     let stderr_bufwtr = BufferWriter::stderr(ColorChoice::Always);
     let mut stdout_bufwtr_boost = BufferedStandardStream::stdout(ColorChoice::Always);
     let mut stderr_bufwtr_boost = BufferedStandardStream::stderr(ColorChoice::Always);
-    let is_nocolor = SETTINGS.read().unwrap().clap_args.nocolor;
-    let is_boost = SETTINGS.read().unwrap().clap_args.boost;
+    let is_stdout_colored = is_colored(&ctx.clap_args, Stream::Stdout);
+    let is_stderr_colored = is_colored(&ctx.clap_args, Stream::Stderr);
+    let is_boost = ctx.clap_args.boost;
+    let output = ctx.clap_args.output;
 
     // Start to capture and color stdout
+    let ctx_clone = Arc::clone(ctx);
     let stdout_thread = thread::spawn(move || {
-        stdout.lines().for_each(|line| {
-            let ln = line.unwrap();
-            if is_nocolor {
-                println!("{}", ln);
-                return;
+        let ctx = ctx_clone;
+        let mut filter = Filter::new(&ctx);
+        let mut print = |line: Line| {
+            for Line {
+                text: ln, style, ..
+            } in filter.push(line)
+            {
+                match output {
+                    OutputFormat::Html => {
+                        color_html(&ctx, ln, style);
+                        continue;
+                    }
+                    OutputFormat::Json => {
+                        color_json(&ctx, "stdout", ln, style);
+                        continue;
+                    }
+                    OutputFormat::Terminal => {}
+                }
+                if !is_stdout_colored {
+                    println!("{}", ln);
+                } else if is_boost {
+                    color_std_boost(&ctx, &mut stdout_bufwtr_boost, ln, style);
+                } else {
+                    color_std(&ctx, &stdout_bufwtr, ln, style);
+                }
             }
-            if is_boost {
-                color_std_boost(&mut stdout_bufwtr_boost, ln);
-            } else {
-                color_std(&stdout_bufwtr, ln);
+        };
+        match &ctx.rewriter {
+            Some(rewriter) => {
+                let mut rewriter = rewriter.lock().unwrap();
+                stdout
+                    .lines()
+                    .flat_map(|line| rewriter.rewrite(line.unwrap()))
+                    .for_each(&mut print);
+                rewriter
+                    .finish(ctx.interrupted.load(Ordering::SeqCst))
+                    .into_iter()
+                    .for_each(&mut print);
             }
-        });
+            None => stdout
+                .lines()
+                .for_each(|line| print(Line::new(line.unwrap()))),
+        }
     });
 
     // Start to capture and color stderr
+    let ctx_clone = Arc::clone(ctx);
     let stderr_thread = thread::spawn(move || {
+        let ctx = ctx_clone;
+        let mut filter = Filter::new(&ctx);
         stderr.lines().for_each(|line| {
-            let ln = line.unwrap();
-            if is_nocolor {
-                eprintln!("{}", ln);
-                return;
-            }
-            if is_boost {
-                color_std_boost(&mut stderr_bufwtr_boost, ln);
-            } else {
-                color_std(&stderr_bufwtr, ln);
+            for Line {
+                text: ln, style, ..
+            } in filter.push(Line::new(line.unwrap()))
+            {
+                match output {
+                    OutputFormat::Html => {
+                        color_html(&ctx, ln, style);
+                        continue;
+                    }
+                    OutputFormat::Json => {
+                        color_json(&ctx, "stderr", ln, style);
+                        continue;
+                    }
+                    OutputFormat::Terminal => {}
+                }
+                if !is_stderr_colored {
+                    eprintln!("{}", ln);
+                } else if is_boost {
+                    color_std_boost(&ctx, &mut stderr_bufwtr_boost, ln, style);
+                } else {
+                    color_std(&ctx, &stderr_bufwtr, ln, style);
+                }
             }
         });
     });
 
-    let status = subcommand_proc.write().unwrap().wait().unwrap();
-    let exit_code = match status.code() {
-        Some(code) => code,
-        None => 0,
+    // Ctrl-c from a terminal reaches the subcommand as well, it's only killed if it's still
+    // running after KILL_DELAY, e.g. blocked or when only ufc got the signal
+    let mut interrupted_at = None;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) => {}
+            Err(e) => {
+                eprintln!("ufc: {}: {}", ctx.argv[0], e);
+                break None;
+            }
+        }
+        if ctx.interrupted.load(Ordering::SeqCst)
+            && interrupted_at.get_or_insert_with(Instant::now).elapsed() >= KILL_DELAY
+        {
+            // An error means it has just exited
+            let _ = child.kill();
+            break child.wait().ok();
+        }
+        thread::sleep(POLL_INTERVAL);
     };
+    // Killed by a signal, e.g. the ctrl-c
+    let exit_code = status.and_then(|status| status.code()).unwrap_or(0);
 
     // Wait a longer here to make sure the subcommand exits
     // and to correctly capture the last word of the sub program
@@ -316,14 +566,10 @@ fn exec(arg_start: usize, subcommand_proc: &mut Arc<RwLock<Child>>) -> i32 {
     return exit_code;
 }
 
-fn color_std(bufwtr: &BufferWriter, ln: String) {
+fn color_std(ctx: &Context, bufwtr: &BufferWriter, ln: String, style: &'static Style) {
     let mut buffer = bufwtr.buffer();
 
-    let mut main_string = vec![ColorString {
-        text: ln,
-        color: &Colors::Default,
-    }];
-    let main_string = colored_output(&mut main_string);
+    let main_string = colored_output(ctx, &ln, style);
 
     for str in main_string.iter() {
         buffer.set_color(&get_color(str.color)).unwrap();
@@ -334,12 +580,13 @@ fn color_std(bufwtr: &BufferWriter, ln: String) {
     bufwtr.print(&buffer).unwrap();
 }
 
-fn color_std_boost(bufwtr: &mut BufferedStandardStream, ln: String) {
-    let mut main_string = vec![ColorString {
-        text: ln,
-        color: &Colors::Default,
-    }];
-    let main_string = colored_output(&mut main_string);
+fn color_std_boost(
+    ctx: &Context,
+    bufwtr: &mut BufferedStandardStream,
+    ln: String,
+    style: &'static Style,
+) {
+    let main_string = colored_output(ctx, &ln, style);
 
     for str in main_string.iter() {
         bufwtr.set_color(&get_color(str.color)).unwrap();
@@ -348,219 +595,177 @@ fn color_std_boost(bufwtr: &mut BufferedStandardStream, ln: String) {
     writeln!(bufwtr,).unwrap();
 }
 
-fn get_color(color: &Colors) -> ColorSpec {
-    let mut col = ColorSpec::new();
-    match color {
-        Colors::UnChanged => col // This one should be unreable
-            .set_fg(Some(Color::Magenta))
-            .set_intense(true)
-            .set_underline(true),
-        Colors::Default => col.set_fg(None),
-        Colors::BDefault => col.set_bold(true).set_fg(None),
-        Colors::Black => col.set_fg(Some(Color::Black)),
-        Colors::Blue => col.set_fg(Some(Color::Blue)),
-        Colors::Green => col.set_fg(Some(Color::Green)),
-        Colors::Red => col.set_fg(Some(Color::Red)),
-        Colors::Cyan => col.set_fg(Some(Color::Cyan)),
-        Colors::Magenta => col.set_fg(Some(Color::Magenta)),
-        Colors::Yellow => col.set_fg(Some(Color::Yellow)),
-        Colors::White => col.set_fg(Some(Color::White)),
-        Colors::BBlack => col.set_bold(true).set_fg(Some(Color::Ansi256(8))),
-        Colors::BBlue => col.set_bold(true).set_fg(Some(Color::Blue)),
-        Colors::BGreen => col.set_bold(true).set_fg(Some(Color::Green)),
-        Colors::BRed => col.set_bold(true).set_fg(Some(Color::Red)),
-        Colors::BCyan => col.set_bold(true).set_fg(Some(Color::Cyan)),
-        Colors::BMagenta => col.set_bold(true).set_fg(Some(Color::Magenta)),
-        Colors::BYellow => col.set_bold(true).set_fg(Some(Color::Yellow)),
-        Colors::BWhite => col.set_bold(true).set_fg(Some(Color::White)),
-        Colors::UDefault => col.set_underline(true).set_fg(None),
-        Colors::UBlack => col.set_underline(true).set_fg(Some(Color::Black)),
-        Colors::UBlue => col.set_underline(true).set_fg(Some(Color::Blue)),
-        Colors::UGreen => col.set_underline(true).set_fg(Some(Color::Green)),
-        Colors::URed => col.set_underline(true).set_fg(Some(Color::Red)),
-        Colors::UCyan => col.set_underline(true).set_fg(Some(Color::Cyan)),
-        Colors::UMagenta => col.set_underline(true).set_fg(Some(Color::Magenta)),
-        Colors::UYellow => col.set_underline(true).set_fg(Some(Color::Yellow)),
-        Colors::UWhite => col.set_underline(true).set_fg(Some(Color::White)),
-        Colors::UBBlack => col
-            .set_underline(true)
-            .set_bold(true)
-            .set_fg(Some(Color::Black)),
-        Colors::UBBlue => col
-            .set_underline(true)
-            .set_bold(true)
-            .set_fg(Some(Color::Blue)),
-        Colors::UBGreen => col
-            .set_underline(true)
-            .set_bold(true)
-            .set_fg(Some(Color::Green)),
-        Colors::UBRed => col
-            .set_underline(true)
-            .set_bold(true)
-            .set_fg(Some(Color::Red)),
-        Colors::UBCyan => col
-            .set_underline(true)
-            .set_bold(true)
-            .set_fg(Some(Color::Cyan)),
-        Colors::UBMagenta => col
-            .set_underline(true)
-            .set_bold(true)
-            .set_fg(Some(Color::Magenta)),
-        Colors::UBYellow => col
-            .set_underline(true)
-            .set_bold(true)
-            .set_fg(Some(Color::Yellow)),
-        Colors::UBWhite => col
-            .set_underline(true)
-            .set_bold(true)
-            .set_fg(Some(Color::White)),
-        Colors::UBDefault => col.set_underline(true).set_bold(true).set_fg(None),
-        Colors::OnBlack => col.set_bg(Some(Color::Black)),
-        Colors::OnBlue => col.set_bg(Some(Color::Blue)),
-        Colors::OnGreen => col.set_bg(Some(Color::Green)),
-        Colors::OnRed => col.set_bg(Some(Color::Red)),
-        Colors::OnCyan => col.set_bg(Some(Color::Cyan)),
-        Colors::OnMagenta => col.set_bg(Some(Color::Magenta)),
-        Colors::OnYellow => col.set_bg(Some(Color::Yellow)),
-        Colors::OnWhite => col.set_bg(Some(Color::White)),
-        Colors::BlackOnBlack => col.set_bg(Some(Color::Black)).set_fg(Some(Color::Black)),
-        Colors::BlackOnBlue => col.set_bg(Some(Color::Blue)).set_fg(Some(Color::Black)),
-        Colors::BlackOnGreen => col.set_bg(Some(Color::Green)).set_fg(Some(Color::Black)),
-        Colors::BlackOnRed => col.set_bg(Some(Color::Red)).set_fg(Some(Color::Black)),
-        Colors::BlackOnCyan => col.set_bg(Some(Color::Cyan)).set_fg(Some(Color::Black)),
-        Colors::BlackOnMagenta => col.set_bg(Some(Color::Magenta)).set_fg(Some(Color::Black)),
-        Colors::BlackOnYellow => col.set_bg(Some(Color::Yellow)).set_fg(Some(Color::Black)),
-        Colors::BlackOnWhite => col.set_bg(Some(Color::White)).set_fg(Some(Color::Black)),
-        Colors::DDefault => col.set_dimmed(true).set_fg(None),
-        Colors::DBlack => col.set_dimmed(true).set_fg(Some(Color::Black)),
-        Colors::DBlue => col.set_dimmed(true).set_fg(Some(Color::Blue)),
-        Colors::DGreen => col.set_dimmed(true).set_fg(Some(Color::Green)),
-        Colors::DRed => col.set_dimmed(true).set_fg(Some(Color::Red)),
-        Colors::DCyan => col.set_dimmed(true).set_fg(Some(Color::Cyan)),
-        Colors::DMagenta => col.set_dimmed(true).set_fg(Some(Color::Magenta)),
-        Colors::DYellow => col.set_dimmed(true).set_fg(Some(Color::Yellow)),
-        Colors::DWhite => col.set_dimmed(true).set_fg(Some(Color::White)),
-    };
-    col
+// Both stdout and stderr end up in the same page
+fn color_html(ctx: &Context, ln: String, style: &'static Style) {
+    let main_string = colored_output(ctx, &ln, style);
+
+    println!("{}", html::line(&main_string));
 }
 
-fn colored_output<'a>(main_string: &'a mut Vec<ColorString<'a>>) -> &'a Vec<ColorString<'a>> {
-    let mut prev_color = &Colors::Default;
-    for palette in SETTINGS.read().unwrap().palettes.iter() {
-        let mut index = 0;
-        // Instead of using a for loop, the size of main_string will grow so we have to use while loop
-        // https://stackoverflow.com/questions/47338839
-        while index < main_string.len() {
-            // println!("i={} main_string.len()={}", index, main_string.len());
-
-            if !main_string[index].color.eq(&Colors::Default) {
-                index += 1;
-                continue; // Ignore those already been colored
-            }
+// Both stdout and stderr are printed to stdout, tagged with the stream they come from
+fn color_json(ctx: &Context, stream: &str, ln: String, style: &'static Style) {
+    let main_string = colored_output(ctx, &ln, style);
 
-            match palette
-                .regexp
-                .captures(main_string[index].text.as_str())
-                .unwrap()
-            {
-                Some(captures) => {
-                    let str = main_string[index].text.as_str();
-                    let mut colored_strings: Vec<ColorString> = vec![];
-
-                    // Non-matched start
-                    let start = 0;
-                    let end = captures.get(0).unwrap().start();
-                    colored_strings.push(ColorString {
-                        text: String::from_str(&str[start..end]).unwrap(),
-                        color: &Colors::Default,
-                    });
-
-                    // captures[0] -> Full match
-                    // captures[1..] -> Group match
-                    let mut last_start = captures.get(0).unwrap().start();
-                    let full_match_end = captures.get(0).unwrap().end();
-                    let mut is_full_match = false;
-                    for (i, capture) in captures.iter().enumerate() {
-                        if i == 0 {
-                            let mut color = palette.colors[0];
-                            if color == &Colors::UnChanged {
-                                color = prev_color;
-                            }
-                            colored_strings.push(ColorString {
-                                text: String::from_str(&str[last_start..full_match_end]).unwrap(),
-                                color: color,
-                            });
-                            prev_color = color;
-                            continue;
-                        }
-
-                        match capture {
-                            Some(_) => {
-                                if !is_full_match {
-                                    colored_strings.pop();
-                                }
-                                is_full_match = true;
-                            }
-                            None => {
-                                continue;
-                            }
-                        }
-
-                        let start = capture.unwrap().start();
-                        let end = capture.unwrap().end();
-
-                        let mut color = palette.colors[0];
-                        if color == &Colors::UnChanged {
-                            color = prev_color;
-                        }
-
-                        colored_strings.push(ColorString {
-                            text: String::from_str(&str[last_start..start]).unwrap(),
-                            color: &color,
-                        });
-                        prev_color = color;
-
-                        if i < palette.colors.len() {
-                            color = palette.colors[i];
-                            if color == &Colors::UnChanged {
-                                color = prev_color;
-                            }
-                        }
-                        colored_strings.push(ColorString {
-                            text: String::from_str(&str[start..end]).unwrap(),
-                            color: color,
-                        });
-                        prev_color = color;
-
-                        last_start = end;
-                    }
+    println!(
+        "{}",
+        json::line(stream, &main_string, ctx.colorizer.palettes())
+    );
+}
 
-                    // Non-matched end
-                    if !is_full_match {
-                        last_start = captures.get(0).unwrap().end();
-                    }
+// Colorize a line with the palettes of the running subcommand, the parts no palette colors
+// get `style`. --explain or UFC_TRACE also prints how the line is colored to stderr
+pub fn colored_output(ctx: &Context, ln: &str, style: &'static Style) -> Vec<ColorString<'static>> {
+    let spans = if ctx.clap_args.explain {
+        let (spans, trace) = ctx.colorizer.explain_line(ln);
+        let trace: Vec<String> = trace.iter().map(|line| format!("ufc: {}", line)).collect();
+        eprintln!("{}", trace.join("\n"));
+        spans
+    } else {
+        ctx.colorizer.colorize_line(ln)
+    };
+    let main_string = color_strings(ln, spans, style);
+    match &ctx.grep {
+        Some(grep) => highlight(main_string, ln, grep),
+        None => main_string,
+    }
+}
 
-                    colored_strings.push(ColorString {
-                        text: String::from_str(&str[last_start..]).unwrap(),
-                        color: &Colors::Default,
-                    });
+// A part of a line with its color, `rule` is the index of the palette which colored it
+#[derive(Debug)]
+pub struct ColorString<'a> {
+    pub text: String,
+    pub color: &'a Style,
+    pub rule: Option<usize>,
+}
 
-                    // println!("colored_strings={:?}", colored_strings);
-                    // println!("colored_strings={:?}", colored_strings);
+// Cut a line at the bounds of its spans, the parts between them get `style`
+fn color_strings(
+    ln: &str,
+    spans: Vec<Span<'static>>,
+    style: &'static Style,
+) -> Vec<ColorString<'static>> {
+    let mut main_string = vec![];
+    let mut start = 0;
+    for span in spans {
+        if start < span.start {
+            main_string.push(ColorString {
+                text: ln[start..span.start].to_string(),
+                color: style,
+                rule: None,
+            });
+        }
+        main_string.push(ColorString {
+            text: ln[span.start..span.end].to_string(),
+            color: span.style,
+            rule: Some(span.rule),
+        });
+        start = span.end;
+    }
+    if start < ln.len() {
+        main_string.push(ColorString {
+            text: ln[start..].to_string(),
+            color: style,
+            rule: None,
+        });
+    }
+    main_string
+}
 
-                    main_string[index].text = String::new();
-                    main_string.remove(index);
-                    main_string.splice((index)..(index), colored_strings);
-                    index += 1;
-                }
-                None => {}
-            };
-            index += 1;
+// Cut the colored strings of a line at the bounds of the matches of `regex` and underline the
+// matched parts on top of their colors, e.g. the --grep pattern
+pub fn highlight(
+    main_string: Vec<ColorString<'static>>,
+    ln: &str,
+    regex: &Regex,
+) -> Vec<ColorString<'static>> {
+    let matches: Vec<(usize, usize)> = regex
+        .find_iter(ln)
+        .filter_map(Result::ok)
+        .map(|m| (m.start(), m.end()))
+        .filter(|(start, end)| start < end)
+        .collect();
+    if matches.is_empty() {
+        return main_string;
+    }
+    let mut highlighted = vec![];
+    let mut start = 0;
+    for str in main_string {
+        let end = start + str.text.len();
+        let mut bounds = vec![start, end];
+        for &(match_start, match_end) in matches.iter() {
+            bounds.extend(
+                [match_start, match_end]
+                    .iter()
+                    .filter(|&&bound| start < bound && bound < end),
+            );
+        }
+        bounds.sort_unstable();
+        for bound in bounds.windows(2) {
+            let is_matched = matches
+                .iter()
+                .any(|&(match_start, match_end)| match_start <= bound[0] && bound[1] <= match_end);
+            highlighted.push(ColorString {
+                text: ln[bound[0]..bound[1]].to_string(),
+                color: if is_matched {
+                    str.color.highlighted()
+                } else {
+                    str.color
+                },
+                rule: str.rule,
+            });
         }
+        start = end;
     }
+    highlighted
+}
 
-    // Remove empty strings
-    main_string.retain(|color_string| color_string.text != "");
-    // println!("main_string={:?}", main_string);
-    main_string
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_precedence() {
+        let is_colored = |options: &[&str], vars: &[(&str, &str)], is_tty: bool| {
+            let args = ["ufc"].iter().chain(options.iter()).chain(["df"].iter());
+            let clap_args = Opts::parse_from(args);
+            let env = |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| OsString::from(value))
+            };
+            color_decision(&clap_args, env, is_tty)
+        };
+        // Only the tty of the stream decides without any variable
+        assert!(is_colored(&[], &[], true));
+        assert!(!is_colored(&[], &[], false));
+        // NO_COLOR wins over CLICOLOR_FORCE, which wins over TERM=dumb and the tty
+        assert!(!is_colored(
+            &[],
+            &[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")],
+            true
+        ));
+        assert!(is_colored(
+            &[],
+            &[("CLICOLOR_FORCE", "1"), ("TERM", "dumb")],
+            false
+        ));
+        assert!(!is_colored(&[], &[("TERM", "dumb")], true));
+        // Empty ones are unset, as is CLICOLOR_FORCE=0
+        assert!(is_colored(&[], &[("NO_COLOR", "")], true));
+        assert!(!is_colored(&[], &[("CLICOLOR_FORCE", "0")], false));
+        assert!(!is_colored(&[], &[("CLICOLOR_FORCE", "")], false));
+        assert!(is_colored(&[], &[("TERM", "xterm-256color")], true));
+        // The options win over every variable, --nocolor over --color=always
+        assert!(is_colored(
+            &["--color", "always"],
+            &[("NO_COLOR", "1")],
+            false
+        ));
+        assert!(!is_colored(
+            &["--color", "never"],
+            &[("CLICOLOR_FORCE", "1")],
+            true
+        ));
+        assert!(!is_colored(&["--nocolor", "--color", "always"], &[], true));
+    }
 }
@@ -0,0 +1,213 @@
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use crate::cli::registry::{Mapping, Pattern};
+use ufc::{LazyRegex, Palette, Style};
+
+// User-defined palettes live in `<config dir>/palettes/<subcommand>.palette`, one rule per line:
+//
+//     # Timeout
+//     Red           timeout
+//     # User name
+//     Default,BRed  user=(\w+)
+//
+// The first field is a comma separated list of `Style` (same meaning as `Palette.colors`),
+// the rest of the line is the regex. A comment right above a rule becomes its name.
+
+// Other commands colored as a built-in one live in `<config dir>/commands`, one mapping per line:
+//
+//     # name or /regex/ over the command line, then the command it is colored as
+//     podman          docker
+//     lsblk           findmnt
+//     /^ip -s link/   ifconfig
+
+// `$UFC_CONFIG_DIR`, `$XDG_CONFIG_HOME/ufc` or `~/.config/ufc`
+pub fn config_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("UFC_CONFIG_DIR") {
+        return Some(PathBuf::from(dir));
+    }
+    if let Some(dir) = env::var_os("XDG_CONFIG_HOME") {
+        return Some(PathBuf::from(dir).join("ufc"));
+    }
+    home_dir().map(|home| home.join(".config").join("ufc"))
+}
+
+// `$XDG_CACHE_HOME/ufc` or `~/.cache/ufc`
+fn cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("XDG_CACHE_HOME") {
+        return Some(PathBuf::from(dir).join("ufc"));
+    }
+    home_dir().map(|home| home.join(".cache").join("ufc"))
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+// FNV-1a, stable across builds unlike the std hasher so the cache survives upgrades
+fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+// Load the user-defined palettes of a subcommand, they are leaked because they live as long as the process
+pub fn user_palettes(name: &str) -> &'static [Palette<'static>] {
+    let (palettes, errors) = check_user_palettes(name);
+    for e in errors.iter() {
+        eprintln!("ufc: {}", e);
+    }
+    palettes
+}
+
+// Same as `user_palettes` but return the rules which are dropped because of an error, e.g. for `ufc palette lint`
+pub fn check_user_palettes(name: &str) -> (&'static [Palette<'static>], Vec<String>) {
+    let path = match config_dir() {
+        Some(dir) => dir.join("palettes").join(format!("{}.palette", name)),
+        None => return (&[], vec![]),
+    };
+    match fs::read_to_string(&path) {
+        Ok(src) => {
+            let (palettes, errors) = load_palettes(&path, &src);
+            (Box::leak(palettes.into_boxed_slice()), errors)
+        }
+        Err(_) => (&[], vec![]),
+    }
+}
+
+fn load_palettes(path: &Path, src: &str) -> (Vec<Palette<'static>>, Vec<String>) {
+    let key = format!("{:016x}", hash(src.as_bytes()));
+    let cache = cache_dir().map(|dir| dir.join("palettes"));
+    let is_validated = cache
+        .as_ref()
+        .and_then(|cache| fs::read_to_string(cache).ok())
+        .map_or(false, |hashes| hashes.lines().any(|line| line == key));
+
+    let mut palettes = vec![];
+    let mut errors = vec![];
+    let mut comment = None;
+    for (number, line) in src.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            comment = None;
+            continue;
+        }
+        if let Some(text) = line.strip_prefix('#') {
+            comment = Some(text.trim());
+            continue;
+        }
+        let name = comment.take().map_or_else(
+            || format!("{}:{}", path.display(), number + 1),
+            String::from,
+        );
+        match parse_line(line, name) {
+            Ok(palette) => {
+                // A file which has been validated before keeps its regexes lazy, otherwise
+                // compile them now so a typo is reported with its line and the rule dropped
+                // (a lazy one which fails anyway is reported once and matches nothing)
+                if !is_validated {
+                    if let Err(e) = palette.regexp.compile() {
+                        errors.push(format!(
+                            "{}:{}: palette \"{}\": {}",
+                            path.display(),
+                            number + 1,
+                            palette.name,
+                            e
+                        ));
+                        continue;
+                    }
+                }
+                palettes.push(palette);
+            }
+            Err(e) => errors.push(format!("{}:{}: {}", path.display(), number + 1, e)),
+        }
+    }
+
+    if !is_validated && errors.is_empty() {
+        if let Some(cache) = cache {
+            let _ = cache
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| {
+                    fs::OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(&cache)
+                })
+                .and_then(|mut file| writeln!(file, "{}", key));
+        }
+    }
+    (palettes, errors)
+}
+
+pub fn user_mappings() -> Vec<Mapping> {
+    let path = match config_dir() {
+        Some(dir) => dir.join("commands"),
+        None => return vec![],
+    };
+    let src = match fs::read_to_string(&path) {
+        Ok(src) => src,
+        Err(_) => return vec![],
+    };
+
+    let mut mappings = vec![];
+    for (number, line) in src.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match parse_mapping(line) {
+            Ok(mapping) => mappings.push(mapping),
+            Err(e) => eprintln!("ufc: {}:{}: {}", path.display(), number + 1, e),
+        }
+    }
+    mappings
+}
+
+fn parse_mapping(line: &str) -> Result<Mapping, String> {
+    let (pattern, target) = match line.strip_prefix('/') {
+        Some(rest) => match rest.rfind('/') {
+            Some(index) => {
+                let regexp = LazyRegex::from_string(rest[..index].to_string());
+                regexp.compile().map_err(|e| e.to_string())?;
+                (Pattern::Regex(regexp), rest[index + 1..].trim())
+            }
+            None => return Err(format!("missing closing \"/\" in \"{}\"", line)),
+        },
+        None => match line.find(char::is_whitespace) {
+            Some(index) => (
+                Pattern::Name(line[..index].to_string()),
+                line[index..].trim(),
+            ),
+            None => return Err(format!("missing command after \"{}\"", line)),
+        },
+    };
+    if target.is_empty() {
+        return Err(format!("missing command after \"{}\"", line));
+    }
+    Ok(Mapping {
+        pattern,
+        target: target.to_string(),
+    })
+}
+
+fn parse_line(line: &str, name: String) -> Result<Palette<'static>, String> {
+    let (colors, regexp) = match line.find(char::is_whitespace) {
+        Some(index) => (&line[..index], line[index..].trim()),
+        None => return Err(format!("missing regex after \"{}\"", line)),
+    };
+    let colors = colors
+        .split(',')
+        .map(|name| Style::from_name(name).ok_or(format!("unknown color \"{}\"", name)))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Palette {
+        name: Box::leak(name.into_boxed_str()),
+        regexp: LazyRegex::from_string(regexp.to_string()),
+        colors,
+    })
+}
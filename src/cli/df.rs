@@ -1,6 +1,9 @@
-use crate::cli::cli::{pre_exec, Colors, Palette};
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
 use clap::{App, Arg, ArgMatches};
-use fancy_regex::Regex;
+use ufc::Palette;
 
 pub struct Cmd {}
 
@@ -39,6 +42,8 @@ impl Cmd {
                 Arg::new("output")
                     .long("output")
                     .takes_value(true)
+                    .min_values(0)
+                    .require_equals(true)
                     .about("use the output format defined by FIELD_LIST, or print all fields if FIELD_LIST is omitted."),
                 Arg::new("portability")
                     .long("portability")
@@ -72,68 +77,22 @@ impl Cmd {
             .about("df")
     }
 
-    pub fn parse(_app: &ArgMatches) {
-        // print!("{:?}", app);
-        pre_exec(Cmd::palette());
+    pub fn parse(ctx: Context, app: &ArgMatches) -> i32 {
+        pre_exec(ctx, Cmd::palettes_of(app))
     }
 
-    fn palette() -> Vec<Palette<'static>> {
-        vec![
-            // FS
-            Palette {
-                regexp: Regex::new(r#"^(?!Filesystem)(\/[-\w\d.]+)+\s"#).unwrap(),
-                colors: vec![&Colors::Blue, &Colors::BBlue],
-            },
-            // tmpfs lines
-            Palette {
-                regexp: Regex::new(r#"^tmpfs.*"#).unwrap(),
-                colors: vec![&Colors::BBlack],
-            },
-            // Mounted on
-            Palette {
-                regexp: Regex::new(r#"\/$|(\/[-\w\d. ]+)+$"#).unwrap(),
-                colors: vec![&Colors::Green, &Colors::BGreen],
-            },
-            // Use 0-60%
-            Palette {
-                regexp: Regex::new(r#"\s[1-6]?[0-9]%\s"#).unwrap(),
-                colors: vec![&Colors::Green],
-            },
-            // 70-89%
-            Palette {
-                regexp: Regex::new(r#"\s[78][0-9]%\s"#).unwrap(),
-                colors: vec![&Colors::Yellow],
-            },
-            // 90-97%
-            Palette {
-                regexp: Regex::new(r#"\s9[0-7]%\s"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            // Use 98-100%
-            Palette {
-                regexp: Regex::new(r#"\s9[89]%|100%\s"#).unwrap(),
-                colors: vec![&Colors::BRed],
-            },
-            // Size 'T'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\dTi?\s|\b\d{10,12}\b"#).unwrap(),
-                colors: vec![&Colors::BRed],
-            },
-            // Size 'G'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\dGi?\s|\b\d{7,9}\b"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            // Size 'M'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\dMi?\s|\b\d{4,6}\b"#).unwrap(),
-                colors: vec![&Colors::Yellow],
-            },
-            // Size 'K'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\d(K|B)i?\s|\b\d{1,3}\b"#).unwrap(),
-                colors: vec![&Colors::Green],
-            },
-        ]
+    // The columns change with the flags: -i prints inode counts instead of sizes, -T adds the
+    // type after the filesystem and --output picks any of them in any order. -P keeps the
+    // columns of the default output, only its header differs
+    fn palettes_of(app: &ArgMatches) -> &'static [Palette<'static>] {
+        if app.is_present("output") {
+            registry::palettes("df output")
+        } else if app.is_present("inodes") {
+            registry::palettes("df inodes")
+        } else if app.is_present("print-type") {
+            registry::palettes("df type")
+        } else {
+            registry::palettes("df")
+        }
     }
 }
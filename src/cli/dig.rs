@@ -1,6 +1,8 @@
-use crate::cli::cli::{pre_exec, Colors, Palette};
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
 use clap::{App, Arg, ArgMatches};
-use fancy_regex::Regex;
 
 pub struct Cmd {}
 
@@ -31,49 +33,8 @@ impl Cmd {
             .about("dig")
     }
 
-    pub fn parse(_app: &ArgMatches) {
+    pub fn parse(ctx: Context, _app: &ArgMatches) -> i32 {
         // print!("{:?}", app);
-        pre_exec(Cmd::palette());
-    }
-
-    fn palette() -> Vec<Palette<'static>> {
-        vec![
-            // Title
-            Palette {
-                regexp: Regex::new(r#"; <<>> DiG.* <<>> (\S+)"#).unwrap(),
-                colors: vec![&Colors::Default, &Colors::BMagenta],
-            },
-            // comments
-            Palette {
-                regexp: Regex::new(r#"^;;[\s\w]+"#).unwrap(),
-                colors: vec![&Colors::Yellow],
-            },
-            // ipv6
-            Palette {
-                regexp: Regex::new(r#"\t(([0-9a-fA-F]{1,4})?\:\:?[0-9a-fA-F]{1,4})+"#).unwrap(),
-                colors: vec![&Colors::DGreen],
-            },
-            // ip4 address
-            Palette {
-                regexp: Regex::new(r#"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}"#).unwrap(),
-                colors: vec![&Colors::Green],
-            },
-            // line
-            Palette {
-                regexp: Regex::new(r#"^(\S+).*?(\d+)\t(\w+)\t(\w+)\t"#).unwrap(),
-                colors: vec![
-                    &Colors::UnChanged,
-                    &Colors::Magenta,
-                    &Colors::Red,
-                    &Colors::Yellow,
-                    &Colors::Cyan,
-                ],
-            },
-            // domain
-            Palette {
-                regexp: Regex::new(r#"[\S]+\."#).unwrap(),
-                colors: vec![&Colors::BMagenta],
-            },
-        ]
+        pre_exec(ctx, registry::palettes("dig"))
     }
 }
@@ -1,8 +1,19 @@
 use clap::{App, AppSettings, Arg, ArgMatches};
-mod images;
-mod ps;
+pub mod build;
+pub mod compose;
+pub mod events;
+pub mod images;
+pub mod info;
+pub mod inspect;
+pub mod logs;
+pub mod network;
+pub mod ps;
+pub mod stats;
+pub mod system;
+pub mod version;
+pub mod volume;
 
-use super::cli::pre_exec;
+use super::cli::{pre_exec, Context};
 
 pub struct Cmd {}
 
@@ -19,15 +30,45 @@ impl Cmd {
             // .setting(AppSettings::NeedsSubcommandHelp)
             // .setting(AppSettings::SubcommandRequiredElseHelp)
             .setting(AppSettings::ArgRequiredElseHelp)
-            .subcommands(vec![ps::Cmd::new(), images::Cmd::new()])
+            .subcommands(Cmd::subcommands())
             .about("docker")
     }
 
-    pub fn parse(app: &ArgMatches) {
+    // Also the subcommands of the docker compatible CLIs, e.g. podman and nerdctl
+    pub fn subcommands() -> Vec<App<'static>> {
+        vec![
+            build::Cmd::new(),
+            compose::Cmd::new(),
+            events::Cmd::new(),
+            images::Cmd::new(),
+            info::Cmd::new(),
+            inspect::Cmd::new(),
+            logs::Cmd::new(),
+            network::Cmd::new(),
+            ps::Cmd::new(),
+            stats::Cmd::new(),
+            system::Cmd::new(),
+            version::Cmd::new(),
+            volume::Cmd::new(),
+        ]
+    }
+
+    pub fn parse(ctx: Context, app: &ArgMatches) -> i32 {
         match app.subcommand() {
-            Some(("ps", args)) => ps::Cmd::parse(args),
-            Some(("images", args)) => images::Cmd::parse(args),
-            _ => pre_exec(vec![]),
+            Some(("build", args)) => build::Cmd::parse(ctx, args),
+            Some(("compose", args)) => compose::Cmd::parse(ctx, args),
+            Some(("events", args)) => events::Cmd::parse(ctx, args),
+            Some(("images", args)) => images::Cmd::parse(ctx, args),
+            Some(("info", args)) => info::Cmd::parse(ctx, args),
+            Some(("inspect", args)) => inspect::Cmd::parse(ctx, args),
+            Some(("logs", args)) => logs::Cmd::parse(ctx, args),
+            Some(("network", args)) => network::Cmd::parse(ctx, args),
+            Some(("ps", args)) => ps::Cmd::parse(ctx, args),
+            Some(("stats", args)) => stats::Cmd::parse(ctx, args),
+            Some(("system", args)) => system::Cmd::parse(ctx, args),
+            Some(("version", args)) => version::Cmd::parse(ctx, args),
+            Some(("volume", args)) => volume::Cmd::parse(ctx, args),
+            _ => pre_exec(ctx, &[]),
         }
     }
 }
@@ -0,0 +1,58 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("build")
+            .args(&[
+                Arg::new("PATH").about("Build context, a directory, an URL or \"-\""),
+                Arg::new("build-arg")
+                    .long("build-arg")
+                    .takes_value(true)
+                    .about("Set build-time variables"),
+                Arg::new("file")
+                    .long("file")
+                    .short('f')
+                    .takes_value(true)
+                    .about("Name of the Dockerfile (Default is 'PATH/Dockerfile')"),
+                Arg::new("no-cache")
+                    .long("no-cache")
+                    .about("Do not use cache when building the image"),
+                Arg::new("platform")
+                    .long("platform")
+                    .takes_value(true)
+                    .about("Set platform if server is multi-platform capable"),
+                Arg::new("progress")
+                    .long("progress")
+                    .takes_value(true)
+                    .possible_values(&["auto", "plain", "tty"])
+                    .about("Set type of progress output. Use plain to show container output"),
+                Arg::new("pull")
+                    .long("pull")
+                    .about("Always attempt to pull a newer version of the image"),
+                Arg::new("quiet")
+                    .long("quiet")
+                    .short('q')
+                    .about("Suppress the build output and print image ID on success"),
+                Arg::new("tag")
+                    .long("tag")
+                    .short('t')
+                    .takes_value(true)
+                    .about("Name and optionally a tag in the 'name:tag' format"),
+                Arg::new("target")
+                    .long("target")
+                    .takes_value(true)
+                    .about("Set the target build stage to build"),
+            ])
+            .about("docker build")
+    }
+
+    pub fn parse(ctx: Context, _args: &ArgMatches) -> i32 {
+        pre_exec(ctx, registry::palettes("docker build"))
+    }
+}
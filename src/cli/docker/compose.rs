@@ -0,0 +1,67 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, AppSettings, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("compose")
+            .args(&[
+                Arg::new("file")
+                    .long("file")
+                    .short('f')
+                    .takes_value(true)
+                    .about("Compose configuration files"),
+                Arg::new("project-name")
+                    .long("project-name")
+                    .short('p')
+                    .takes_value(true)
+                    .about("Project name"),
+                Arg::new("project-directory")
+                    .long("project-directory")
+                    .takes_value(true)
+                    .about("Specify an alternate working directory"),
+                Arg::new("profile")
+                    .long("profile")
+                    .takes_value(true)
+                    .about("Specify a profile to enable"),
+                Arg::new("env-file")
+                    .long("env-file")
+                    .takes_value(true)
+                    .about("Specify an alternate environment file"),
+            ])
+            .subcommands(vec![App::new("ps")
+                .args(&[
+                    Arg::new("SERVICE")
+                        .multiple_values(true)
+                        .about("Services to list, all services if omitted"),
+                    Arg::new("all").long("all").short('a').about(
+                        "Show all stopped containers (including those created by the run command)",
+                    ),
+                    Arg::new("format")
+                        .long("format")
+                        .takes_value(true)
+                        .about(r#"Format the output. Values: [pretty | json] (default "pretty")"#),
+                    Arg::new("quiet")
+                        .long("quiet")
+                        .short('q')
+                        .about("Only display IDs"),
+                    Arg::new("services")
+                        .long("services")
+                        .about("Display services"),
+                ])
+                .about("docker compose ps")])
+            .setting(AppSettings::ArgRequiredElseHelp)
+            .about("docker compose")
+    }
+
+    pub fn parse(ctx: Context, app: &ArgMatches) -> i32 {
+        match app.subcommand() {
+            Some(("ps", _args)) => pre_exec(ctx, registry::palettes("docker compose")),
+            _ => pre_exec(ctx, &[]),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("events")
+            .args(&[
+                Arg::new("filter")
+                    .long("filter")
+                    .short('f')
+                    .takes_value(true)
+                    .about("Filter output based on conditions provided"),
+                Arg::new("format")
+                    .long("format")
+                    .takes_value(true)
+                    .about("Format the output using the given Go template"),
+                Arg::new("since")
+                    .long("since")
+                    .takes_value(true)
+                    .about("Show all events created since timestamp"),
+                Arg::new("until")
+                    .long("until")
+                    .takes_value(true)
+                    .about("Stream events until this timestamp"),
+            ])
+            .about("docker events")
+    }
+
+    pub fn parse(ctx: Context, _args: &ArgMatches) -> i32 {
+        pre_exec(ctx, registry::palettes("docker events"))
+    }
+}
@@ -0,0 +1,36 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("inspect")
+            .args(&[
+                Arg::new("NAME")
+                    .multiple_values(true)
+                    .about("Names or IDs of the objects to inspect"),
+                Arg::new("format")
+                    .long("format")
+                    .short('f')
+                    .takes_value(true)
+                    .about("Format the output using the given Go template"),
+                Arg::new("size")
+                    .long("size")
+                    .short('s')
+                    .about("Display total file sizes if the type is container"),
+                Arg::new("type")
+                    .long("type")
+                    .takes_value(true)
+                    .about("Return JSON for specified type"),
+            ])
+            .about("docker inspect")
+    }
+
+    pub fn parse(ctx: Context, _args: &ArgMatches) -> i32 {
+        pre_exec(ctx, registry::palettes("docker inspect"))
+    }
+}
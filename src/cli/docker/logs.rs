@@ -0,0 +1,45 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("logs")
+            .args(&[
+                Arg::new("CONTAINER").about("Name or ID of the container"),
+                Arg::new("details")
+                    .long("details")
+                    .about("Show extra details provided to logs"),
+                Arg::new("follow")
+                    .long("follow")
+                    .short('f')
+                    .about("Follow log output"),
+                Arg::new("since")
+                    .long("since")
+                    .takes_value(true)
+                    .about(r#"Show logs since timestamp (e.g. 2013-01-02T13:23:37Z) or relative (e.g. 42m for 42 minutes)"#),
+                Arg::new("tail")
+                    .long("tail")
+                    .short('n')
+                    .takes_value(true)
+                    .about(r#"Number of lines to show from the end of the logs (default "all")"#),
+                Arg::new("timestamps")
+                    .long("timestamps")
+                    .short('t')
+                    .about("Show timestamps"),
+                Arg::new("until")
+                    .long("until")
+                    .takes_value(true)
+                    .about(r#"Show logs before a timestamp (e.g. 2013-01-02T13:23:37Z) or relative (e.g. 42m for 42 minutes)"#),
+            ])
+            .about("docker logs")
+    }
+
+    pub fn parse(ctx: Context, _args: &ArgMatches) -> i32 {
+        pre_exec(ctx, registry::palettes("docker logs"))
+    }
+}
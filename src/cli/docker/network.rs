@@ -0,0 +1,43 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, AppSettings, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("network")
+            .subcommands(vec![App::new("ls")
+                .visible_alias("list")
+                .args(&[
+                    Arg::new("filter")
+                        .long("filter")
+                        .short('f')
+                        .takes_value(true)
+                        .about(r#"Provide filter values (e.g. "driver=bridge")"#),
+                    Arg::new("format")
+                        .long("format")
+                        .takes_value(true)
+                        .about("Pretty-print networks using a Go template"),
+                    Arg::new("no-trunc")
+                        .long("no-trunc")
+                        .about("Do not truncate the output"),
+                    Arg::new("quiet")
+                        .long("quiet")
+                        .short('q')
+                        .about("Only display network IDs"),
+                ])
+                .about("docker network ls")])
+            .setting(AppSettings::ArgRequiredElseHelp)
+            .about("docker network")
+    }
+
+    pub fn parse(ctx: Context, app: &ArgMatches) -> i32 {
+        match app.subcommand() {
+            Some(("ls", _args)) => pre_exec(ctx, registry::palettes("docker network")),
+            _ => pre_exec(ctx, &[]),
+        }
+    }
+}
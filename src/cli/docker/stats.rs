@@ -0,0 +1,37 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("stats")
+            .args(&[
+                Arg::new("CONTAINER")
+                    .multiple_values(true)
+                    .about("Containers to show, all running containers if omitted"),
+                Arg::new("all")
+                    .long("all")
+                    .short('a')
+                    .about("Show all containers (default shows just running)"),
+                Arg::new("format")
+                    .long("format")
+                    .takes_value(true)
+                    .about("Pretty-print images using a Go template"),
+                Arg::new("no-stream")
+                    .long("no-stream")
+                    .about("Disable streaming stats and only pull the first result"),
+                Arg::new("no-trunc")
+                    .long("no-trunc")
+                    .about("Do not truncate output"),
+            ])
+            .about("docker stats")
+    }
+
+    pub fn parse(ctx: Context, _args: &ArgMatches) -> i32 {
+        pre_exec(ctx, registry::palettes("docker stats"))
+    }
+}
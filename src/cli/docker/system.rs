@@ -0,0 +1,34 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, AppSettings, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("system")
+            .subcommands(vec![App::new("df")
+                .args(&[
+                    Arg::new("format")
+                        .long("format")
+                        .takes_value(true)
+                        .about("Pretty-print images using a Go template"),
+                    Arg::new("verbose")
+                        .long("verbose")
+                        .short('v')
+                        .about("Show detailed information on space usage"),
+                ])
+                .about("docker system df")])
+            .setting(AppSettings::ArgRequiredElseHelp)
+            .about("docker system")
+    }
+
+    pub fn parse(ctx: Context, app: &ArgMatches) -> i32 {
+        match app.subcommand() {
+            Some(("df", _args)) => pre_exec(ctx, registry::palettes("docker system")),
+            _ => pre_exec(ctx, &[]),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("version")
+            .args(&[Arg::new("format")
+                .long("format")
+                .short('f')
+                .takes_value(true)
+                .about("Format the output using the given Go template")])
+            .about("docker version")
+    }
+
+    pub fn parse(ctx: Context, _args: &ArgMatches) -> i32 {
+        pre_exec(ctx, registry::palettes("docker version"))
+    }
+}
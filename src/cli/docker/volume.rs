@@ -0,0 +1,40 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, AppSettings, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("volume")
+            .subcommands(vec![App::new("ls")
+                .visible_alias("list")
+                .args(&[
+                    Arg::new("filter")
+                        .long("filter")
+                        .short('f')
+                        .takes_value(true)
+                        .about(r#"Provide filter values (e.g. "dangling=true")"#),
+                    Arg::new("format")
+                        .long("format")
+                        .takes_value(true)
+                        .about("Pretty-print volumes using a Go template"),
+                    Arg::new("quiet")
+                        .long("quiet")
+                        .short('q')
+                        .about("Only display volume names"),
+                ])
+                .about("docker volume ls")])
+            .setting(AppSettings::ArgRequiredElseHelp)
+            .about("docker volume")
+    }
+
+    pub fn parse(ctx: Context, app: &ArgMatches) -> i32 {
+        match app.subcommand() {
+            Some(("ls", _args)) => pre_exec(ctx, registry::palettes("docker volume")),
+            _ => pre_exec(ctx, &[]),
+        }
+    }
+}
@@ -1,6 +1,8 @@
-use crate::cli::cli::{pre_exec, Colors, Palette};
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
 use clap::{App, Arg, ArgMatches};
-use fancy_regex::Regex;
 
 pub struct Cmd {}
 
@@ -40,60 +42,8 @@ impl Cmd {
             .about("du")
     }
 
-    pub fn parse(_app: &ArgMatches) {
+    pub fn parse(ctx: Context, _app: &ArgMatches) -> i32 {
         // print!("{:?}", app);
-        pre_exec(Cmd::palette());
-    }
-
-    fn palette() -> Vec<Palette<'static>> {
-        vec![
-            // Path
-            Palette {
-                regexp: Regex::new(r#"\s+[\./]+([\w\s\-\_\.]+)(/.*)?$"#).unwrap(),
-                colors: vec![&Colors::Default, &Colors::BBlue, &Colors::Blue],
-            },
-            // Total
-            Palette {
-                regexp: Regex::new(r#"(.*)\s+(total)$"#).unwrap(),
-                colors: vec![&Colors::BYellow],
-            },
-            // Size 'T'
-            Palette {
-                regexp: Regex::new(r#"^ ?\d*[.,]?\dTi?"#).unwrap(),
-                colors: vec![&Colors::BRed],
-            },
-            // Size 'G'
-            Palette {
-                regexp: Regex::new(r#"^ ?\d*[.,]?\dGi?"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            Palette {
-                regexp: Regex::new(r#"^\d{7,9}"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            // Size 'M'
-            Palette {
-                regexp: Regex::new(r#"^ ?\d*[.,]?\dMi?"#).unwrap(),
-                colors: vec![&Colors::Yellow],
-            },
-            Palette {
-                regexp: Regex::new(r#"^\d{4,6}"#).unwrap(),
-                colors: vec![&Colors::Yellow],
-            },
-            // Size 'K'
-            Palette {
-                regexp: Regex::new(r#"^ ?\d*[.,]?\dKi?"#).unwrap(),
-                colors: vec![&Colors::Green],
-            },
-            Palette {
-                regexp: Regex::new(r#"^\d{1,3}"#).unwrap(),
-                colors: vec![&Colors::Green],
-            },
-            // Cannot read error
-            Palette {
-                regexp: Regex::new(r#"^du.*"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-        ]
+        pre_exec(ctx, registry::palettes("du"))
     }
 }
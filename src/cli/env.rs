@@ -1,6 +1,8 @@
-use crate::cli::cli::{pre_exec, Colors, Palette};
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
 use clap::{App, Arg, ArgMatches};
-use fancy_regex::Regex;
 
 pub struct Cmd {}
 
@@ -24,23 +26,8 @@ impl Cmd {
             .about("env")
     }
 
-    pub fn parse(_app: &ArgMatches) {
+    pub fn parse(ctx: Context, _app: &ArgMatches) -> i32 {
         // print!("{:?}", app);
-        pre_exec(Cmd::palette());
-    }
-
-    fn palette() -> Vec<Palette<'static>> {
-        vec![
-            // Main
-            Palette {
-                regexp: Regex::new(r#"^([^=]+)(=)(.*)$"#).unwrap(),
-                colors: vec![
-                    &Colors::Default,
-                    &Colors::Cyan,
-                    &Colors::White,
-                    &Colors::Yellow,
-                ],
-            },
-        ]
+        pre_exec(ctx, registry::palettes("env"))
     }
 }
@@ -1,6 +1,8 @@
-use crate::cli::cli::{pre_exec, Colors, Palette};
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
 use clap::{App, AppSettings, Arg, ArgMatches};
-use fancy_regex::Regex;
 
 pub struct Cmd {}
 
@@ -33,63 +35,8 @@ impl Cmd {
             .about(r#"fdisk"#)
     }
 
-    pub fn parse(_app: &ArgMatches) {
+    pub fn parse(ctx: Context, _app: &ArgMatches) -> i32 {
         // print!("{:?}", app);
-        pre_exec(Cmd::palette());
-    }
-
-    fn palette() -> Vec<Palette<'static>> {
-        vec![
-            // Size 'G'
-            Palette {
-                regexp: Regex::new(r#"\s\d+[.,]?\d*\s?Gi?B?"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            // Size 'M'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\d*\s?Mi?B?"#).unwrap(),
-                colors: vec![&Colors::Yellow],
-            },
-            // Size 'K'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\d*\s?Ki?B?"#).unwrap(),
-                colors: vec![&Colors::Green],
-            },
-            // ID
-            Palette {
-                regexp: Regex::new(r#"identifier: (.*)$"#).unwrap(),
-                colors: vec![&Colors::UnChanged, &Colors::Cyan],
-            },
-            // Type
-            Palette {
-                regexp: Regex::new(r#"type: (.*)$"#).unwrap(),
-                colors: vec![&Colors::UnChanged, &Colors::BCyan],
-            },
-            // Partitions
-            Palette {
-                regexp: Regex::new(r#"^(?:\/([^\/: ]+))+"#).unwrap(),
-                colors: vec![&Colors::Green, &Colors::BGreen],
-            },
-            // Boot?
-            Palette {
-                regexp: Regex::new(r#"\*\s\s\s"#).unwrap(),
-                colors: vec![&Colors::OnRed, &Colors::BWhite],
-            },
-            // Disk
-            Palette {
-                regexp: Regex::new(r#"^(Disk) (?:\/([^\/: ]+))+"#).unwrap(),
-                colors: vec![
-                    &Colors::Yellow,
-                    &Colors::OnYellow,
-                    &Colors::BYellow,
-                    &Colors::BYellow,
-                ],
-            },
-            // Error
-            Palette {
-                regexp: Regex::new(r#"fdisk: cannot open ([^:]+).*$"#).unwrap(),
-                colors: vec![&Colors::Red, &Colors::BRed],
-            },
-        ]
+        pre_exec(ctx, registry::palettes("fdisk"))
     }
 }
@@ -0,0 +1,210 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use crate::cli::cli::{Context, Line};
+use ufc::{LazyRegex, Style};
+
+// Where the level of a line is looked for, in order: a level field (`level=error`,
+// `"severity":"WARN"`), a bracketed one (`[error]`, `[core:warn]`) or an upper case word (`ERROR`)
+static LEVEL_PATTERNS: [LazyRegex; 3] = [
+    LazyRegex::new(r#"(?i)\b(?:level|lvl|severity|loglevel|priority)"?\s*[=:]\s*"?(\w+)"#),
+    LazyRegex::new(
+        r#"(?i)[\[<](?:\w+:)?(emerg|emergency|alert|crit|critical|fatal|panic|err|error|warn|warning|notice|info|debug|trace)[\]>]"#,
+    ),
+    LazyRegex::new(
+        r#"\b(EMERG|ALERT|CRIT|CRITICAL|FATAL|PANIC|ERR|ERROR|WARN|WARNING|NOTICE|INFO|DEBUG|TRACE)\b"#,
+    ),
+];
+
+// Severity of a line like the syslog priorities, the most severe first
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Level {
+    Emerg,
+    Alert,
+    Crit,
+    Error,
+    Warning,
+    Notice,
+    Info,
+    Debug,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    // A name or a syslog priority, also the numeric levels of pino and bunyan (10 to 60)
+    fn from_str(src: &str) -> Result<Self, String> {
+        match src.to_lowercase().as_str() {
+            "emerg" | "emergency" | "0" => Ok(Self::Emerg),
+            "alert" | "1" => Ok(Self::Alert),
+            "crit" | "critical" | "fatal" | "panic" | "2" | "60" => Ok(Self::Crit),
+            "err" | "error" | "3" | "50" => Ok(Self::Error),
+            "warn" | "warning" | "4" | "40" => Ok(Self::Warning),
+            "notice" | "5" => Ok(Self::Notice),
+            "info" | "6" | "30" => Ok(Self::Info),
+            "debug" | "trace" | "7" | "10" | "20" => Ok(Self::Debug),
+            _ => Err(format!("invalid level \"{}\"", src)),
+        }
+    }
+}
+
+impl Level {
+    // The level written in a line, if any
+    pub fn of(ln: &str) -> Option<Self> {
+        LEVEL_PATTERNS.iter().find_map(|regex| {
+            regex
+                .captures(ln)
+                .ok()
+                .flatten()
+                .and_then(|captures| captures.get(1))
+                .and_then(|level| level.as_str().parse().ok())
+        })
+    }
+}
+
+// --grep, --min-level and --context: which lines of a stream are printed. Each stream has its own
+// filter, run line by line in its reader thread like `grep -C`
+pub struct Filter<'a> {
+    grep: Option<&'a LazyRegex>,
+    min_level: Option<Level>,
+    context: usize,
+    // The last lines which didn't match, printed before the next match
+    before: VecDeque<Line>,
+    // Number of lines still printed after the last match
+    after: usize,
+    // Level of the last line which has one, a line without a level belongs to the entry above,
+    // e.g. a stack trace
+    level: Option<Level>,
+    is_printed: bool,
+    // A line was dropped since the last printed one, "--" separates the groups of lines like grep
+    is_skipped: bool,
+}
+
+impl<'a> Filter<'a> {
+    pub fn new(ctx: &'a Context) -> Self {
+        Self::with(
+            ctx.grep.as_ref(),
+            ctx.clap_args.min_level,
+            ctx.clap_args.context,
+        )
+    }
+
+    pub fn with(grep: Option<&'a LazyRegex>, min_level: Option<Level>, context: usize) -> Self {
+        Self {
+            grep,
+            min_level,
+            context,
+            before: VecDeque::new(),
+            after: 0,
+            level: None,
+            is_printed: false,
+            is_skipped: false,
+        }
+    }
+
+    // The lines to print once `line` is read: the ones kept before it and itself, or nothing yet
+    pub fn push(&mut self, mut line: Line) -> Vec<Line> {
+        if self.grep.is_none() && self.min_level.is_none() {
+            return vec![line];
+        }
+        if let Some(level) = line.level.or_else(|| Level::of(&line.text)) {
+            self.level = Some(level);
+        }
+        line.level = self.level;
+
+        let is_grepped = self
+            .grep
+            .map_or(true, |grep| grep.is_match(&line.text).unwrap_or(false));
+        // Lines above the first one with a level are kept
+        let is_severe = match (self.min_level, line.level) {
+            (Some(min_level), Some(level)) => level <= min_level,
+            _ => true,
+        };
+        if is_grepped && is_severe {
+            let mut lines = vec![];
+            if self.is_printed && self.is_skipped && self.context > 0 {
+                lines.push(Line {
+                    text: String::from("--"),
+                    style: &Style::DDefault,
+                    level: None,
+                });
+            }
+            lines.extend(self.before.drain(..));
+            lines.push(line);
+            self.after = self.context;
+            self.is_printed = true;
+            self.is_skipped = false;
+            lines
+        } else if self.after > 0 {
+            self.after -= 1;
+            vec![line]
+        } else {
+            if self.before.len() == self.context {
+                self.is_skipped = true;
+                self.before.pop_front();
+            }
+            if self.context > 0 {
+                self.before.push_back(line);
+            }
+            vec![]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(filter: &mut Filter, lines: &[&str]) -> Vec<String> {
+        lines
+            .iter()
+            .flat_map(|ln| filter.push(Line::new(ln.to_string())))
+            .map(|line| line.text)
+            .collect()
+    }
+
+    #[test]
+    fn grep_with_context() {
+        let grep = LazyRegex::new("fail");
+        let lines = ["a", "b", "fail 1", "c", "d", "e", "f", "fail 2", "g"];
+        assert_eq!(
+            run(&mut Filter::with(Some(&grep), None, 1), &lines),
+            ["b", "fail 1", "c", "--", "f", "fail 2", "g"]
+        );
+        assert_eq!(
+            run(&mut Filter::with(Some(&grep), None, 0), &lines),
+            ["fail 1", "fail 2"]
+        );
+    }
+
+    #[test]
+    fn min_level_keeps_unleveled_lines_of_an_entry() {
+        let lines = [
+            "10:01 INFO started",
+            "10:02 ERROR failed",
+            "  at main.rs:1",
+            r#"{"level":"warn","msg":"slow"}"#,
+            "level=debug msg=polling",
+            "  polled",
+        ];
+        assert_eq!(
+            run(&mut Filter::with(None, Some(Level::Warning), 0), &lines),
+            [
+                "10:02 ERROR failed",
+                "  at main.rs:1",
+                r#"{"level":"warn","msg":"slow"}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn levels() {
+        assert_eq!(
+            Level::of("[Tue Jun 15] [core:error] [pid 1]"),
+            Some(Level::Error)
+        );
+        assert_eq!(Level::of(r#"{"level":50}"#), Some(Level::Error));
+        assert_eq!(Level::of("W0615 warning: disk"), None);
+        assert_eq!("warn".parse::<Level>(), Ok(Level::Warning));
+        assert!("loud".parse::<Level>().is_err());
+    }
+}
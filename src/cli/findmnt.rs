@@ -1,6 +1,8 @@
-use crate::cli::cli::{pre_exec, Colors, Palette};
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
 use clap::{App, Arg, ArgMatches};
-use fancy_regex::Regex;
 
 pub struct Cmd {}
 
@@ -53,48 +55,8 @@ impl Cmd {
             .about("findmnt")
     }
 
-    pub fn parse(_app: &ArgMatches) {
+    pub fn parse(ctx: Context, _app: &ArgMatches) -> i32 {
         // print!("{:?}", app);
-        pre_exec(Cmd::palette());
-    }
-
-    fn palette() -> Vec<Palette<'static>> {
-        vec![
-            // MS Types
-            Palette {
-                regexp: Regex::new(r#"\b(fat|vfat|ntfs|msdos)\b"#).unwrap(),
-                colors: vec![&Colors::OnCyan],
-            },
-            // Common Types
-            Palette {
-                regexp: Regex::new(r#"\b(ext\d|xfs|btrfs|nfs)\b"#).unwrap(),
-                colors: vec![&Colors::Cyan],
-            },
-            // Like comment, leave at end always
-            Palette {
-                regexp: Regex::new(r#"^.*(?=cgroup|tmpfs).*$"#).unwrap(),
-                colors: vec![&Colors::BBlack],
-            },
-            // RO
-            Palette {
-                regexp: Regex::new(r#"(?:\s)ro"#).unwrap(),
-                colors: vec![&Colors::BGreen],
-            },
-            // RW
-            Palette {
-                regexp: Regex::new(r#"(?:\s)rw"#).unwrap(),
-                colors: vec![&Colors::BRed],
-            },
-            // Mount Path
-            Palette {
-                regexp: Regex::new(r#"(?<=─|-)(?:\/([^\/ ]+))+"#).unwrap(),
-                colors: vec![&Colors::UnChanged, &Colors::BYellow],
-            },
-            // Devices
-            Palette {
-                regexp: Regex::new(r#"\s\/dev(?:\/([^\/ ]+))+"#).unwrap(),
-                colors: vec![&Colors::Green, &Colors::BGreen],
-            },
-        ]
+        pre_exec(ctx, registry::palettes("findmnt"))
     }
 }
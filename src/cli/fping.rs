@@ -0,0 +1,48 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("fping")
+            .args(&[
+                Arg::new("targets").multiple_values(true).about("Targets to ping, hostnames or IP addresses"),
+                Arg::new("alive").long("alive").short('a').about("Show targets that are alive."),
+                Arg::new("addr").long("addr").short('A').about("Display targets by address rather than DNS name."),
+                Arg::new("size").long("size").short('b').takes_value(true).about("Number of bytes of ping data to send."),
+                Arg::new("backoff").long("backoff").short('B').takes_value(true).about("Backoff factor on timeouts."),
+                Arg::new("count").long("count").short('c').takes_value(true).about("Number of request packets to send to each target, a summary of each target is printed at the end."),
+                Arg::new("vcount").long("vcount").short('C').takes_value(true).about("Similar to -c, but the per-target statistics are displayed as all the response times of the target."),
+                Arg::new("rdns").long("rdns").short('d').about("Use DNS to lookup address of return ping packet."),
+                Arg::new("timestamp").long("timestamp").short('D').about("Add Unix timestamps in front of output lines generated with -l, -c, or -C."),
+                Arg::new("elapsed").long("elapsed").short('e').about("Show elapsed (round-trip) time of packets."),
+                Arg::new("file").long("file").short('f').takes_value(true).about("Read list of targets from a file."),
+                Arg::new("generate").long("generate").short('g').about("Generate a target list from a supplied IP netmask, or a starting and ending IP."),
+                Arg::new("ttl").long("ttl").short('H').takes_value(true).about("Set the IP TTL field (time to live hops)."),
+                Arg::new("interval").long("interval").short('i').takes_value(true).about("The minimum amount of time (in milliseconds) between sending a ping packet to any target."),
+                Arg::new("loop").long("loop").short('l').about("Loop sending packets to each target indefinitely."),
+                Arg::new("all").long("all").short('m').about("Send pings to each of a target host's multiple IP addresses."),
+                Arg::new("name").long("name").short('n').about("If targets are specified as IP addresses, do a reverse-DNS lookup on them to print hostnames in the output."),
+                Arg::new("netdata").long("netdata").short('N').about("Format output for netdata (-l -Q are required)."),
+                Arg::new("outage").long("outage").short('o').about("Calculate \"outage time\" based on the number of lost pings and the interval used."),
+                Arg::new("period").long("period").short('p').takes_value(true).about("In looping or counting modes (-l, -c, or -C), this parameter sets the time in milliseconds that fping waits between successive packets to an individual target."),
+                Arg::new("quiet").long("quiet").short('q').about("Quiet. Don't show per-probe results, but only the final summary."),
+                Arg::new("squiet").long("squiet").short('Q').takes_value(true).about("Like -q, but additionally show interval summary results every n seconds."),
+                Arg::new("retry").long("retry").short('r').takes_value(true).about("Retry limit."),
+                Arg::new("stats").long("stats").short('s').about("Print cumulative statistics upon exit."),
+                Arg::new("src").long("src").short('S').takes_value(true).about("Set source address."),
+                Arg::new("timeout").long("timeout").short('t').takes_value(true).about("Initial target timeout in milliseconds."),
+                Arg::new("unreach").long("unreach").short('u').about("Show targets that are unreachable."),
+                Arg::new("version").long("version").short('v').about("Print fping version information."),
+            ])
+            .about("fping")
+    }
+
+    pub fn parse(ctx: Context, _app: &ArgMatches) -> i32 {
+        pre_exec(ctx, registry::palettes("fping"))
+    }
+}
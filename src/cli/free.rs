@@ -1,6 +1,8 @@
-use crate::cli::cli::{pre_exec, Colors, Palette};
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
 use clap::{App, Arg, ArgMatches};
-use fancy_regex::Regex;
 
 pub struct Cmd {}
 
@@ -32,48 +34,11 @@ impl Cmd {
             .about("free")
     }
 
-    pub fn parse(_app: &ArgMatches) {
-        // print!("{:?}", app);
-        pre_exec(Cmd::palette());
-    }
-
-    fn palette() -> Vec<Palette<'static>> {
-        vec![
-            // Zero
-            Palette {
-                regexp: Regex::new(r#"\s+0\w?(\s|$)"#).unwrap(),
-                colors: vec![&Colors::Green],
-            },
-            // Swap
-            Palette {
-                regexp: Regex::new(r#"^Swap"#).unwrap(),
-                colors: vec![&Colors::BMagenta],
-            },
-            // Mem
-            Palette {
-                regexp: Regex::new(r#"^Mem"#).unwrap(),
-                colors: vec![&Colors::BCyan],
-            },
-            // Size 'T'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\dTi?|\b\d{10,12}\b"#).unwrap(),
-                colors: vec![&Colors::BRed],
-            },
-            // Size 'G'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\dGi?|\b\d{7,9}\b"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            // Size 'M'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\dMi?|\b\d{4,6}\b"#).unwrap(),
-                colors: vec![&Colors::Yellow],
-            },
-            // Size 'K'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\dKi?|\b\d{1,3}\b"#).unwrap(),
-                colors: vec![&Colors::Green],
-            },
-        ]
+    pub fn parse(ctx: Context, app: &ArgMatches) -> i32 {
+        if app.is_present("bytes") {
+            pre_exec(ctx, registry::palettes("free bytes"))
+        } else {
+            pre_exec(ctx, registry::palettes("free"))
+        }
     }
 }
@@ -0,0 +1,89 @@
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::cli::registry::{self, samples};
+use ufc::Colorizer;
+
+// Golden tests: captured outputs in `tests/golden/<profile>/*.txt` ("docker ps" -> "docker-ps")
+// are colorized with the built-in palettes of the profile and compared with the spans
+// stored next to them in `*.spans`, one block per line:
+//
+//     > /dev/sda1  20G
+//       0..9 Blue "Filesystem"
+//
+// After an intentional palette change, accept the new spans with
+//
+//     UFC_UPDATE_GOLDEN=1 cargo test golden
+//
+// and review the diff of the `*.spans` files.
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+}
+
+fn render(colorizer: &Colorizer, sample: &str) -> String {
+    let mut spans = String::new();
+    for line in sample.lines() {
+        writeln!(spans, "> {}", line).unwrap();
+        for span in colorizer.colorize_line(line) {
+            writeln!(
+                spans,
+                "  {}..{} {} {:?}",
+                span.start,
+                span.end,
+                span.style.name(),
+                colorizer.palettes()[span.rule].name
+            )
+            .unwrap();
+        }
+    }
+    spans
+}
+
+#[test]
+fn golden() {
+    let is_update = env::var_os("UFC_UPDATE_GOLDEN").is_some();
+    let mut failures = vec![];
+    for (profile, path) in registry::profiles().flat_map(|profile| {
+        samples(&golden_dir(), profile.name)
+            .into_iter()
+            .map(move |path| (profile, path))
+    }) {
+        let colorizer = Colorizer::new(profile.palettes.iter().collect());
+        let actual = render(&colorizer, &fs::read_to_string(&path).unwrap());
+        let spans_path = path.with_extension("spans");
+        if is_update {
+            fs::write(&spans_path, &actual).unwrap();
+            continue;
+        }
+        match fs::read_to_string(&spans_path) {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => {
+                let expected = expected.lines().collect::<Vec<_>>();
+                let actual = actual.lines().collect::<Vec<_>>();
+                let number = (0..expected.len().max(actual.len()))
+                    .find(|&i| expected.get(i) != actual.get(i))
+                    .unwrap_or(expected.len());
+                failures.push(format!(
+                    "{}:{}:\n  expected: {}\n  actual:   {}",
+                    spans_path.display(),
+                    number + 1,
+                    expected.get(number).unwrap_or(&"<eof>"),
+                    actual.get(number).unwrap_or(&"<eof>")
+                ));
+            }
+            Err(_) => failures.push(format!("{}: missing", spans_path.display())),
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{}\n\nRun `UFC_UPDATE_GOLDEN=1 cargo test golden` to accept the new spans",
+        failures.join("\n")
+    );
+}
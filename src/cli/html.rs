@@ -0,0 +1,162 @@
+use std::fmt::Write;
+
+use termcolor::{Color, ColorSpec};
+
+use crate::cli::cli::ColorString;
+use ufc::{get_color, Style};
+
+// Render the colored output as a standalone html page, each `Style` is a css class
+// whose style is derived from the same `ColorSpec` used for the terminal
+
+pub fn header(title: &str) -> String {
+    let mut page = String::new();
+    writeln!(page, "<!DOCTYPE html>").unwrap();
+    writeln!(page, "<html>").unwrap();
+    writeln!(page, "<head>").unwrap();
+    writeln!(page, r#"<meta charset="utf-8">"#).unwrap();
+    writeln!(page, "<title>{}</title>", escape(title)).unwrap();
+    writeln!(page, "<style>").unwrap();
+    writeln!(
+        page,
+        "body {{ background: #1e1e1e; color: #d4d4d4; }}\npre {{ font-family: monospace; }}"
+    )
+    .unwrap();
+    for color in Style::ALL.iter() {
+        writeln!(
+            page,
+            ".{} {{ {} }}",
+            color.name(),
+            get_css(&get_color(color))
+        )
+        .unwrap();
+    }
+    writeln!(page, "</style>").unwrap();
+    writeln!(page, "</head>").unwrap();
+    writeln!(page, "<body>").unwrap();
+    write!(page, "<pre>").unwrap();
+    page
+}
+
+pub fn footer() -> String {
+    String::from("</pre>\n</body>\n</html>")
+}
+
+pub fn line(main_string: &[ColorString]) -> String {
+    let mut line = String::new();
+    for str in main_string.iter() {
+        if str.color == &Style::Default {
+            line.push_str(&escape(&str.text));
+        } else {
+            write!(
+                line,
+                r#"<span class="{}">{}</span>"#,
+                str.color.name(),
+                escape(&str.text)
+            )
+            .unwrap();
+        }
+    }
+    line
+}
+
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn get_css(spec: &ColorSpec) -> String {
+    let mut css = String::new();
+    if let Some(fg) = spec.fg() {
+        write!(css, "color: {}; ", get_css_color(fg)).unwrap();
+    }
+    if let Some(bg) = spec.bg() {
+        write!(css, "background: {}; ", get_css_color(bg)).unwrap();
+    }
+    if spec.bold() {
+        css.push_str("font-weight: bold; ");
+    }
+    if spec.underline() {
+        css.push_str("text-decoration: underline; ");
+    }
+    if spec.dimmed() {
+        css.push_str("opacity: 0.6; ");
+    }
+    css.trim_end().to_string()
+}
+
+// Style of a common dark terminal theme
+fn get_css_color(color: &Color) -> &'static str {
+    match color {
+        Color::Black => "#000000",
+        Color::Blue => "#2472c8",
+        Color::Green => "#0dbc79",
+        Color::Red => "#cd3131",
+        Color::Cyan => "#11a8cd",
+        Color::Magenta => "#bc3fbc",
+        Color::Yellow => "#e5e510",
+        Color::White => "#e5e5e5",
+        Color::Ansi256(8) => "#666666",
+        _ => "inherit",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn str(text: &str, color: &'static Style) -> ColorString<'static> {
+        ColorString {
+            text: String::from(text),
+            color,
+            rule: None,
+        }
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            escape(r#"<a href="x">Tom & Jerry</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&lt;/a&gt;"
+        );
+        // Already escaped text is escaped again, it's printed as is
+        assert_eq!(escape("&amp; 'é'"), "&amp;amp; 'é'");
+    }
+
+    #[test]
+    fn lines() {
+        // Spans are side by side and never nested, the uncolored text has none
+        assert_eq!(
+            line(&[
+                str("<", &Style::Default),
+                str("err", &Style::BRed),
+                str("&", &Style::Yellow),
+                str("\"x\"", &Style::Default),
+            ]),
+            r#"&lt;<span class="BRed">err</span><span class="Yellow">&amp;</span>&quot;x&quot;"#
+        );
+        assert_eq!(line(&[]), "");
+    }
+
+    #[test]
+    fn page() {
+        let header = header("df -h <&>");
+        assert!(header.starts_with("<!DOCTYPE html>\n<html>\n<head>\n"));
+        assert!(header.contains("<title>df -h &lt;&amp;&gt;</title>"));
+        assert!(header.ends_with("<body>\n<pre>"));
+        // A class for every style, with the css of its terminal color
+        for style in Style::ALL.iter() {
+            assert!(header.contains(&format!("\n.{} {{", style.name())));
+        }
+        assert!(header.contains(".BRed { color: #cd3131; font-weight: bold; }"));
+        assert_eq!(footer(), "</pre>\n</body>\n</html>");
+    }
+}
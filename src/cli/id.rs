@@ -1,6 +1,8 @@
-use crate::cli::cli::{pre_exec, Colors, Palette};
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
 use clap::{App, Arg, ArgMatches};
-use fancy_regex::Regex;
 
 pub struct Cmd {}
 
@@ -49,34 +51,8 @@ impl Cmd {
             .about("id")
     }
 
-    pub fn parse(_app: &ArgMatches) {
+    pub fn parse(ctx: Context, _app: &ArgMatches) -> i32 {
         // print!("{:?}", app);
-        pre_exec(Cmd::palette());
-    }
-
-    fn palette() -> Vec<Palette<'static>> {
-        vec![
-            // SELinux
-            Palette {
-                regexp: Regex::new(r#"(\w+_u):(\w+_r):(\w+_t):([\w\-.:]+)"#).unwrap(),
-                colors: vec![
-                    &Colors::UnChanged,
-                    &Colors::Green,
-                    &Colors::Yellow,
-                    &Colors::Cyan,
-                    &Colors::Magenta,
-                ],
-            },
-            // User
-            Palette {
-                regexp: Regex::new(r#"uid.(\d+)\((\w+)\)"#).unwrap(),
-                colors: vec![&Colors::UnChanged, &Colors::Green, &Colors::BGreen],
-            },
-            // Groups
-            Palette {
-                regexp: Regex::new(r#"(\d+)\((\w+)\)"#).unwrap(),
-                colors: vec![&Colors::UnChanged, &Colors::Yellow, &Colors::BYellow],
-            },
-        ]
+        pre_exec(ctx, registry::palettes("id"))
     }
 }
@@ -1,6 +1,14 @@
-use crate::cli::cli::{pre_exec, Colors, Palette};
-use clap::{App, Arg, ArgMatches};
-use fancy_regex::Regex;
+use std::{convert::TryFrom, fmt::Write, sync::Mutex};
+
+use crate::cli::{
+    cli::{is_colored, pre_exec, Context, Line, OutputFormat, Rewrite},
+    registry,
+};
+use atty::Stream;
+use chrono::{DateTime, Local, Utc};
+use clap::{App, Arg, ArgMatches, ArgSettings};
+use serde_json::{Map, Value};
+use ufc::{Palette, Style};
 
 pub struct Cmd {}
 
@@ -69,103 +77,389 @@ impl Cmd {
             .about("journalctl")
     }
 
-    pub fn parse(_app: &ArgMatches) {
-        // print!("{:?}", app);
-        pre_exec(Cmd::palette());
+    pub fn parse(mut ctx: Context, app: &ArgMatches) -> i32 {
+        let is_shown = ctx.clap_args.output != OutputFormat::Terminal
+            || is_colored(&ctx.clap_args, Stream::Stdout);
+        if is_shown && Cmd::is_short(app) {
+            // -o short doesn't print the priority of the entries, ask for them as json
+            // (one line each) and print them as -o short again, colored by their priority
+            // Only the options of journalctl, e.g. not those of "sudo"
+            let start = ctx.argv.len() - registry::effective_argv(&ctx.argv).len();
+            let mut argv = ctx.argv.split_off(start);
+            Cmd::output_json(&mut argv);
+            ctx.argv.extend(argv);
+            ctx.rewriter = Some(Mutex::new(Box::new(Priorities::new(
+                !app.is_present("no-hostname"),
+                app.is_present("utc"),
+            ))));
+        }
+        pre_exec(ctx, Cmd::palettes_of(app))
+    }
+
+    // Replace the -o short of the command line with --output=json where it is, or add it
+    fn output_json(argv: &mut Vec<String>) {
+        let app = Cmd::new();
+        let takes_value = |short: char| {
+            app.get_arguments()
+                .any(|arg| arg.get_short() == Some(short) && arg.is_set(ArgSettings::TakesValue))
+        };
+        let json = String::from("--output=json");
+        let mut args = argv.split_off(1).into_iter();
+        let mut is_replaced = false;
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                argv.push(arg);
+                argv.extend(args.by_ref());
+                break;
+            }
+            if arg == "--output" || arg.starts_with("--output=") {
+                if arg == "--output" {
+                    args.next();
+                }
+                argv.push(json.clone());
+                is_replaced = true;
+                continue;
+            }
+            // "-o short", "-oshort" or "-fo short", but not the value of another option, "-tfoo"
+            let shorts = arg
+                .strip_prefix('-')
+                .filter(|shorts| !shorts.starts_with('-'));
+            let short_o = shorts.and_then(|shorts| {
+                shorts
+                    .char_indices()
+                    .find(|(_, short)| *short == 'o' || takes_value(*short))
+                    .filter(|(_, short)| *short == 'o')
+                    .map(|(index, _)| (&shorts[..index], &shorts[index + 1..]))
+            });
+            match short_o {
+                Some((before, value)) => {
+                    if !before.is_empty() {
+                        argv.push(format!("-{}", before));
+                    }
+                    if value.is_empty() {
+                        args.next();
+                    }
+                    argv.push(json.clone());
+                    is_replaced = true;
+                }
+                None => argv.push(arg),
+            }
+        }
+        if !is_replaced {
+            argv.insert(1, json);
+        }
+    }
+
+    // Entries printed in the default -o short, not the commands which print something else
+    fn is_short(app: &ArgMatches) -> bool {
+        let others = [
+            // The explanations aren't in the json output
+            "catalog",
+            "list-boots",
+            "disk-usage",
+            "fields",
+            "field",
+            "header",
+            "verify",
+            "list-catalog",
+            "dump-catalog",
+            "update-catalog",
+            "setup-keys",
+            "vacuum-size",
+            "vacuum-time",
+            "sync",
+            "flush",
+            "relinquish-var",
+            "smart-relinquish-var",
+            "rotate",
+            "help",
+            "version",
+        ];
+        matches!(app.value_of("output"), None | Some("short"))
+            && !others.iter().any(|name| app.is_present(name))
+    }
+
+    // The output modes and the commands which don't print entries look nothing alike,
+    // e.g. "-o json" or "--list-boots"
+    fn palettes_of(app: &ArgMatches) -> &'static [Palette<'static>] {
+        if app.is_present("list-boots") {
+            return registry::palettes("journalctl list-boots");
+        }
+        if app.is_present("disk-usage") {
+            return registry::palettes("journalctl disk-usage");
+        }
+        if app.is_present("fields") {
+            return registry::palettes("journalctl fields");
+        }
+        match app.value_of("output") {
+            Some("json") | Some("json-pretty") | Some("json-sse") | Some("json-seq") => {
+                registry::palettes("journalctl json")
+            }
+            Some("verbose") | Some("export") => registry::palettes("journalctl verbose"),
+            // short, short-iso, short-precise, short-monotonic, cat, ...
+            _ => registry::palettes("journalctl"),
+        }
+    }
+}
+
+// Styles of the priorities: emerg, alert, crit, err, warning, notice, info and debug
+const PRIORITY_STYLES: [&Style; 8] = [
+    &Style::BRed,
+    &Style::BRed,
+    &Style::BRed,
+    &Style::Red,
+    &Style::Yellow,
+    &Style::Default,
+    &Style::Default,
+    &Style::DDefault,
+];
+
+// Prints the entries of `journalctl -o json`, one line each, like -o short, e.g.
+//
+//     {"__REALTIME_TIMESTAMP":"1623722560654321","PRIORITY":"3","_HOSTNAME":"host",
+//      "SYSLOG_IDENTIFIER":"sshd","_PID":"23420","MESSAGE":"error: kex_exchange_identification: ..."}
+//
+// becomes "Jun 15 10:02:40 host sshd[23420]: error: kex_exchange..." in the style of PRIORITY=3,
+// as soon as its line is read so `journalctl -f` isn't held back
+#[derive(Debug)]
+pub struct Priorities {
+    is_hostname: bool,
+    // --utc, the timestamps are in the local time zone otherwise
+    is_utc: bool,
+    // Boot of the last entry, -o short prints "-- Reboot --" between two boots
+    boot_id: Option<String>,
+}
+
+impl Priorities {
+    pub fn new(is_hostname: bool, is_utc: bool) -> Self {
+        Self {
+            is_hostname,
+            is_utc,
+            boot_id: None,
+        }
+    }
+
+    fn entry(&mut self, fields: &Map<String, Value>) -> Vec<Line> {
+        let values: Vec<(&str, String)> = fields
+            .iter()
+            .filter_map(|(key, value)| Some((key.as_str(), field_value(value)?)))
+            .collect();
+        let field = |name: &str| {
+            values
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.as_str())
+        };
+
+        let mut lines = vec![];
+        if let Some(boot_id) = field("_BOOT_ID") {
+            if self
+                .boot_id
+                .as_deref()
+                .map_or(false, |last| last != boot_id)
+            {
+                lines.push(Line::new(String::from("-- Reboot --")));
+            }
+            self.boot_id = Some(boot_id.to_string());
+        }
+
+        let mut prefix = field("_SOURCE_REALTIME_TIMESTAMP")
+            .or_else(|| field("__REALTIME_TIMESTAMP"))
+            .and_then(|usec| usec.parse::<i64>().ok())
+            .map(|usec| self.short_timestamp(usec / 1_000_000))
+            .unwrap_or_default();
+        if let Some(hostname) = field("_HOSTNAME").filter(|_| self.is_hostname) {
+            write!(prefix, " {}", hostname).unwrap();
+        }
+        if let Some(identifier) = field("SYSLOG_IDENTIFIER").or_else(|| field("_COMM")) {
+            write!(prefix, " {}", identifier).unwrap();
+            if let Some(pid) = field("SYSLOG_PID").or_else(|| field("_PID")) {
+                write!(prefix, "[{}]", pid).unwrap();
+            }
+        }
+        prefix.push(':');
+        let prefix = prefix.trim_start().to_string();
+        let priority = field("PRIORITY");
+        let style = priority
+            .and_then(|priority| priority.parse::<usize>().ok())
+            .and_then(|priority| PRIORITY_STYLES.get(priority).copied())
+            .unwrap_or(&Style::Default);
+        let level = priority.and_then(|priority| priority.parse().ok());
+
+        // The lines of a multi-line message are aligned with the first one
+        lines.extend(
+            field("MESSAGE")
+                .unwrap_or_default()
+                .trim_end_matches('\n')
+                .split('\n')
+                .enumerate()
+                .map(|(index, line)| Line {
+                    text: match index {
+                        0 => format!("{} {}", prefix, line),
+                        _ => format!("{:width$} {}", "", line, width = prefix.len()),
+                    },
+                    style,
+                    level,
+                }),
+        );
+        lines
+    }
+
+    // Seconds since the epoch to "Jun 15 10:02:40"
+    fn short_timestamp(&self, time: i64) -> String {
+        let time = match DateTime::<Utc>::from_timestamp(time, 0) {
+            Some(time) => time,
+            None => return String::new(),
+        };
+        let format = "%b %d %H:%M:%S";
+        match self.is_utc {
+            true => time.format(format).to_string(),
+            false => time.with_timezone(&Local).format(format).to_string(),
+        }
+    }
+}
+
+impl Rewrite for Priorities {
+    fn rewrite(&mut self, ln: String) -> Vec<Line> {
+        match serde_json::from_str(&ln) {
+            Ok(fields) => self.entry(&fields),
+            // Not an entry, e.g. a message of journalctl
+            Err(_) => vec![Line::new(ln)],
+        }
+    }
+}
+
+// The value of a field of `journalctl -o json` as a string. A binary value is an array of bytes,
+// a field set more than once the array of its values (the first one is kept) and a value too
+// large to be printed null
+fn field_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(string) => Some(string.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Array(values) => values
+            .iter()
+            .filter(|value| !value.is_u64())
+            .find_map(field_value)
+            .or_else(|| {
+                let bytes: Vec<u8> = values
+                    .iter()
+                    .filter_map(|value| u8::try_from(value.as_u64()?).ok())
+                    .collect();
+                Some(String::from_utf8_lossy(&bytes).into_owned())
+            }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rewrite(json: &str) -> Vec<(String, &'static Style)> {
+        let mut priorities = Priorities::new(true, true);
+        json.lines()
+            .flat_map(|ln| priorities.rewrite(ln.to_string()))
+            .map(|line| (line.text, line.style))
+            .collect()
+    }
+
+    #[test]
+    fn short_lines_by_priority() {
+        let lines = rewrite(
+            r#"{"__REALTIME_TIMESTAMP":"1623751272123456","_BOOT_ID":"0a1b","PRIORITY":"6","_HOSTNAME":"host","_COMM":"systemd","_PID":"1","MESSAGE":"Started Session 42 of user user."}
+{"__REALTIME_TIMESTAMP":"1623751360654321","_BOOT_ID":"0a1b","PRIORITY":"3","_HOSTNAME":"host","SYSLOG_IDENTIFIER":"app","SYSLOG_PID":"7","_PID":"8","MESSAGE":"Traceback (most recent call last):\n  File \"app.py\", line 1\nValueError"}
+{"__REALTIME_TIMESTAMP":"1623751500000000","_BOOT_ID":"2c3d","PRIORITY":"4","_HOSTNAME":"host","SYSLOG_IDENTIFIER":"kernel","MESSAGE":[108,111,119,32,109,101,109,111,114,121]}
+Failed to get journal fields: Bad message"#,
+        );
+        assert_eq!(
+            lines,
+            vec![
+                (
+                    String::from(
+                        "Jun 15 10:01:12 host systemd[1]: Started Session 42 of user user."
+                    ),
+                    &Style::Default
+                ),
+                (
+                    String::from("Jun 15 10:02:40 host app[7]: Traceback (most recent call last):"),
+                    &Style::Red
+                ),
+                (
+                    String::from("                               File \"app.py\", line 1"),
+                    &Style::Red
+                ),
+                (
+                    String::from("                             ValueError"),
+                    &Style::Red
+                ),
+                (String::from("-- Reboot --"), &Style::Default),
+                (
+                    String::from("Jun 15 10:05:00 host kernel: low memory"),
+                    &Style::Yellow
+                ),
+                (
+                    String::from("Failed to get journal fields: Bad message"),
+                    &Style::Default
+                ),
+            ]
+        );
+    }
+
+    // `journalctl -f` waits for the next entry, the last one is printed without it
+    #[test]
+    fn entry_of_an_endless_stream() {
+        let mut priorities = Priorities::new(false, true);
+        let lines = priorities.rewrite(String::from(
+            r#"{"__REALTIME_TIMESTAMP":"1623751360654321","PRIORITY":"2","_HOSTNAME":"host","SYSLOG_IDENTIFIER":"sshd","_PID":"23420","MESSAGE":"fatal: échec 🔥"}"#,
+        ));
+        assert_eq!(lines.len(), 1);
+        assert_eq!(
+            lines[0].text,
+            "Jun 15 10:02:40 sshd[23420]: fatal: échec 🔥"
+        );
+        assert_eq!(lines[0].style, &Style::BRed);
+    }
+
+    #[test]
+    fn field_values() {
+        let value = |json: &str| field_value(&serde_json::from_str(json).unwrap());
+        assert_eq!(
+            value(r#""tab\t\u00e9\ud83d\udd25""#),
+            Some(String::from("tab\té🔥"))
+        );
+        assert_eq!(value(r#"[104,105]"#), Some(String::from("hi")));
+        // Set twice, as text then as binary
+        assert_eq!(value(r#"["first",[104,105]]"#), Some(String::from("first")));
+        assert_eq!(value(r#"[[104,105],"second"]"#), Some(String::from("hi")));
+        assert_eq!(value(r#"null"#), None);
     }
 
-    fn palette() -> Vec<Palette<'static>> {
-        vec![
-            // Connect requires special attention
-            Palette {
-                regexp: Regex::new(r#"connect"#).unwrap(),
-                colors: vec![&Colors::OnRed],
-            },
-            // Status deferred
-            Palette {
-                regexp: Regex::new(r#"status\=deferred|Connection refused"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            // 5xx status
-            Palette {
-                regexp: Regex::new(r#"\s\b5\d{2}\b\s"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            // 4xx status
-            Palette {
-                regexp: Regex::new(r#"\s\b4\d{2}\b\s"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            // 3xx status
-            Palette {
-                regexp: Regex::new(r#"\s\b3\d{2}\b\s"#).unwrap(),
-                colors: vec![&Colors::Yellow],
-            },
-            // 2xx status
-            Palette {
-                regexp: Regex::new(r#"\s\b2\d{2}\b\s"#).unwrap(),
-                colors: vec![&Colors::Green],
-            },
-            // HTTP verbs
-            Palette {
-                regexp: Regex::new(r#"GET|POST|PUT|DELETE|PATCH|HEAD"#).unwrap(),
-                colors: vec![&Colors::Green],
-            },
-            // Email address
-            Palette {
-                regexp: Regex::new(r#"[a-zA-z0-9\.\-\+]+\@[\w\-\.]+"#).unwrap(),
-                colors: vec![&Colors::Green],
-            },
-            // Date and hostname
-            Palette {
-                regexp: Regex::new(r#"^... (\d| )\d \d\d:\d\d:\d\d(\s[-.\w\d]+?\s)"#).unwrap(),
-                colors: vec![&Colors::Green, &Colors::Green, &Colors::Yellow],
-            },
-            // IPv6
-            Palette {
-                regexp: Regex::new(r#"\b[0-9a-fA-F]{1,4}(\:\:?[0-9a-fA-F]{1,4})+"#).unwrap(),
-                colors: vec![&Colors::BYellow],
-            },
-            // IPv4 or IPv4:Port
-            Palette {
-                regexp: Regex::new(r#"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}(:\d{1,5})?"#).unwrap(),
-                colors: vec![&Colors::BYellow],
-            },
-            // Name of process and pid
-            Palette {
-                regexp: Regex::new(r#"([\w/\.\-]+)(\[\d+?\])"#).unwrap(),
-                colors: vec![&Colors::BBlue, &Colors::BRed],
-            },
-            // Everything in <>
-            Palette {
-                regexp: Regex::new(r#"\<.*?\>"#).unwrap(),
-                colors: vec![&Colors::Blue],
-            },
-            // This is probably a pathname
-            Palette {
-                regexp: Regex::new(r#"\s/[a-zA-Z_/\.\-\?\d\=\&]+"#).unwrap(),
-                colors: vec![&Colors::Blue],
-            },
-            // Everything in "
-            Palette {
-                regexp: Regex::new(r#"\".*?\""#).unwrap(),
-                colors: vec![&Colors::Blue],
-            },
-            // Everything in `'
-            Palette {
-                regexp: Regex::new(r#"\`.+?\'"#).unwrap(),
-                colors: vec![&Colors::BYellow],
-            },
-            // Everything in parentheses
-            Palette {
-                regexp: Regex::new(r#"\(.*?\)"#).unwrap(),
-                colors: vec![&Colors::Blue],
-            },
-            // Display this line in yellow and stop further processing
-            Palette {
-                regexp: Regex::new(r#".*last message repeated \d+ times$"#).unwrap(),
-                colors: vec![&Colors::Yellow],
-            },
-        ]
+    #[test]
+    fn output_option_replaced() {
+        let argv = |cmdline: &str| {
+            let mut argv: Vec<String> = cmdline.split(' ').map(String::from).collect();
+            Cmd::output_json(&mut argv);
+            argv.join(" ")
+        };
+        assert_eq!(argv("journalctl -f"), "journalctl --output=json -f");
+        assert_eq!(
+            argv("journalctl -o short -f"),
+            "journalctl --output=json -f"
+        );
+        assert_eq!(
+            argv("journalctl -fo short -n 5"),
+            "journalctl -f --output=json -n 5"
+        );
+        assert_eq!(argv("journalctl -oshort"), "journalctl --output=json");
+        assert_eq!(
+            argv("journalctl --output=short"),
+            "journalctl --output=json"
+        );
+        assert_eq!(
+            argv("journalctl --output short -b"),
+            "journalctl --output=json -b"
+        );
+        // The value of -t, not -o
+        assert_eq!(argv("journalctl -tfoo"), "journalctl --output=json -tfoo");
     }
 }
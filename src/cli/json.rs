@@ -0,0 +1,118 @@
+use std::fmt::Write;
+
+use crate::cli::cli::ColorString;
+use ufc::{Palette, Style};
+
+// Render a colored line as one json object (NDJSON), e.g.
+// {"stream":"stdout","text":"/dev/sda1 20G","spans":[{"start":0,"end":9,"style":"Blue","rule":0,"name":"FS"}]}
+// `start` and `end` are byte offsets into `text`, `rule` is the index of the palette which produced the span
+// and `name` its name, see `ufc palette list <subcommand>`
+
+pub fn line(stream: &str, main_string: &[ColorString], palettes: &[&Palette]) -> String {
+    let mut text = String::new();
+    let mut spans = vec![];
+    for str in main_string.iter() {
+        let start = text.len();
+        text.push_str(&str.text);
+        if str.color == &Style::Default {
+            continue;
+        }
+        let mut span = String::new();
+        write!(
+            span,
+            r#"{{"start":{},"end":{},"style":"{}","rule":"#,
+            start,
+            text.len(),
+            str.color.name()
+        )
+        .unwrap();
+        match str.rule {
+            Some(rule) => write!(
+                span,
+                r#"{},"name":"{}"}}"#,
+                rule,
+                escape(palettes[rule].name)
+            )
+            .unwrap(),
+            None => span.push_str(r#"null,"name":null}"#),
+        }
+        spans.push(span);
+    }
+    format!(
+        r#"{{"stream":"{}","text":"{}","spans":[{}]}}"#,
+        stream,
+        escape(&text),
+        spans.join(",")
+    )
+}
+
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ufc::LazyRegex;
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            escape("say \"hi\" C:\\tmp\n\r\t\u{1b}[0m\u{7f}é"),
+            "say \\\"hi\\\" C:\\\\tmp\\n\\r\\t\\u001b[0m\u{7f}é"
+        );
+    }
+
+    #[test]
+    fn lines() {
+        let palettes = [
+            Palette {
+                name: "Mount \"point\"",
+                regexp: LazyRegex::new(r#"/\S*"#),
+                colors: vec![&Style::Blue],
+            },
+            Palette {
+                name: "Size",
+                regexp: LazyRegex::new(r#"\d+G"#),
+                colors: vec![&Style::Green],
+            },
+        ];
+        let str = |text: &str, color: &'static Style, rule: Option<usize>| ColorString {
+            text: String::from(text),
+            color,
+            rule,
+        };
+        // Offsets in bytes, "é" is 2 of them, and the uncolored parts have no span
+        let main_string = [
+            str("/mnt/é", &Style::Blue, Some(0)),
+            str(" ", &Style::Default, None),
+            str("20G", &Style::Green, Some(1)),
+            str(" \"ok\"", &Style::Red, None),
+        ];
+        assert_eq!(
+            line("stderr", &main_string, &palettes.iter().collect::<Vec<_>>()),
+            concat!(
+                r#"{"stream":"stderr","text":"/mnt/é 20G \"ok\"","spans":["#,
+                r#"{"start":0,"end":7,"style":"Blue","rule":0,"name":"Mount \"point\""},"#,
+                r#"{"start":8,"end":11,"style":"Green","rule":1,"name":"Size"},"#,
+                r#"{"start":11,"end":16,"style":"Red","rule":null,"name":null}]}"#
+            )
+        );
+        assert_eq!(
+            line("stdout", &[], &[]),
+            r#"{"stream":"stdout","text":"","spans":[]}"#
+        );
+    }
+}
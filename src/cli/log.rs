@@ -0,0 +1,505 @@
+use std::{
+    fs::{self, File, Metadata},
+    io::{BufRead, BufReader, Read, Seek, SeekFrom},
+    path::PathBuf,
+    str::FromStr,
+    sync::atomic::Ordering,
+    thread,
+    time::Duration,
+};
+
+use atty::Stream;
+use clap::{App, Arg, ArgMatches};
+use termcolor::{BufferWriter, ColorChoice};
+use ufc::{Colorizer, LazyRegex, Palette, Style};
+
+use crate::cli::{
+    cli::{is_colored, print_line, Context, Line, OutputFormat},
+    config,
+    filter::Filter,
+    html, registry,
+};
+
+// What the first lines of each format look like, the format matching most of them wins
+static SIGNATURES: [(Format, LazyRegex); 5] = [
+    (
+        Format::Syslog,
+        LazyRegex::new(
+            r#"^(?:\w{3} [\d ]\d \d\d:\d\d:\d\d|\d{4}-\d\d-\d\dT\d\d:\d\d:\d\d\S*) \S+ [^\s:\[]+(?:\[\d+\])?:\s"#,
+        ),
+    ),
+    (
+        Format::Combined,
+        LazyRegex::new(r#"^\S+ \S+ \S+ \[[^\]]+\] "[^"]*" \d{3} (?:\d+|-)"#),
+    ),
+    (
+        Format::Error,
+        LazyRegex::new(
+            r#"^\[\w{3} \w{3} [\d ]\d \d\d:\d\d:\d\d(?:\.\d+)? \d{4}\] \[|^\d{4}/\d\d/\d\d \d\d:\d\d:\d\d \[\w+\] \d+#\d+: "#,
+        ),
+    ),
+    (Format::Json, LazyRegex::new(r#"^\s*\{.*\}\s*$"#)),
+    (
+        Format::Logfmt,
+        LazyRegex::new(
+            r#"^\s*[\w.\-]+=(?:"(?:[^"\\]|\\.)*"|\S*)(?:\s+[\w.\-]+=(?:"(?:[^"\\]|\\.)*"|\S*))+\s*$"#,
+        ),
+    ),
+];
+
+// Formats of the log files, each one colored by its own palettes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    // /var/log/syslog, /var/log/messages, ... colored like `journalctl`
+    Syslog,
+    // Access logs of nginx and apache, also the common log format without referrer and user agent
+    Combined,
+    // error.log of nginx and error_log of apache
+    Error,
+    Json,
+    Logfmt,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        match src {
+            "syslog" => Ok(Self::Syslog),
+            "combined" => Ok(Self::Combined),
+            "error" => Ok(Self::Error),
+            "json" => Ok(Self::Json),
+            "logfmt" => Ok(Self::Logfmt),
+            _ => Err(format!("invalid log format \"{}\"", src)),
+        }
+    }
+}
+
+impl Format {
+    pub fn palettes(self) -> &'static [Palette<'static>] {
+        match self {
+            Self::Syslog => registry::palettes("journalctl"),
+            Self::Combined => registry::palettes("log combined"),
+            Self::Error => registry::palettes("log error"),
+            Self::Json | Self::Logfmt => registry::palettes("structured"),
+        }
+    }
+
+    // The format most of the first lines look like, syslog when none of them looks like anything
+    pub fn detect<'a>(lines: impl IntoIterator<Item = &'a str>) -> Self {
+        let lines: Vec<&str> = lines
+            .into_iter()
+            .filter(|ln| !ln.trim().is_empty())
+            .take(20)
+            .collect();
+        SIGNATURES
+            .iter()
+            .map(|(format, regex)| {
+                let count = lines
+                    .iter()
+                    .filter(|ln| regex.is_match(ln).unwrap_or(false))
+                    .count();
+                (count, *format)
+            })
+            .filter(|(count, _)| *count > 0)
+            // The first of the formats matching as many lines
+            .rev()
+            .max_by_key(|(count, _)| *count)
+            .map_or(Self::Syslog, |(_, format)| format)
+    }
+}
+
+// A log file followed like `tail -F`: reopened from its start when it's replaced by a new file
+// (rotated) or truncated, and waited for while it's missing
+struct Followed {
+    path: PathBuf,
+    reader: Option<BufReader<File>>,
+    // Inode of the opened file, to tell when the path points to another file
+    id: u64,
+    // Offset of the next byte to read
+    pos: u64,
+    // The last line until its newline is written
+    partial: Vec<u8>,
+    format: Option<Format>,
+}
+
+impl Followed {
+    fn new(path: &str, format: Option<Format>) -> Self {
+        Self {
+            path: PathBuf::from(path),
+            reader: None,
+            id: 0,
+            pos: 0,
+            partial: vec![],
+            format,
+        }
+    }
+
+    // Open the file at the start of its last `lines` lines, all of them if `None`
+    fn open(&mut self, lines: Option<usize>) -> std::io::Result<()> {
+        let mut file = File::open(&self.path)?;
+        self.id = file_id(&file.metadata()?);
+        self.pos = match lines {
+            Some(lines) => tail_offset(&mut file, lines)?,
+            None => 0,
+        };
+        file.seek(SeekFrom::Start(self.pos))?;
+        self.reader = Some(BufReader::new(file));
+        Ok(())
+    }
+
+    // The lines written since the last poll, what happened to the file is reported to stderr
+    fn poll(&mut self) -> Vec<String> {
+        let mut lines = self.read_lines();
+        let path = self.path.clone();
+        let path = path.display();
+        match fs::metadata(&self.path) {
+            Err(_) if self.reader.is_some() => {
+                eprintln!("ufc: {}: has become inaccessible", path);
+                lines.extend(self.take_partial());
+                self.reader = None;
+            }
+            Err(_) => {}
+            Ok(metadata) if self.reader.is_none() || file_id(&metadata) != self.id => {
+                let is_replaced = self.reader.is_some();
+                if self.open(None).is_ok() {
+                    if is_replaced {
+                        eprintln!("ufc: {}: has been replaced, following new file", path);
+                    } else {
+                        eprintln!("ufc: {}: has appeared, following new file", path);
+                    }
+                    lines.extend(self.take_partial());
+                    lines.extend(self.read_lines());
+                }
+            }
+            Ok(metadata) if metadata.len() < self.pos => {
+                eprintln!("ufc: {}: file truncated", path);
+                if self.open(None).is_ok() {
+                    lines.extend(self.take_partial());
+                    lines.extend(self.read_lines());
+                }
+            }
+            Ok(_) => {}
+        }
+        lines
+    }
+
+    fn read_lines(&mut self) -> Vec<String> {
+        let mut lines = vec![];
+        let reader = match self.reader.as_mut() {
+            Some(reader) => reader,
+            None => return lines,
+        };
+        loop {
+            match reader.read_until(b'\n', &mut self.partial) {
+                Ok(0) => break,
+                Ok(size) => {
+                    self.pos += size as u64;
+                    if self.partial.ends_with(b"\n") {
+                        lines.extend(take_line(&mut self.partial));
+                    }
+                }
+                Err(e) => {
+                    eprintln!("ufc: {}: {}", self.path.display(), e);
+                    break;
+                }
+            }
+        }
+        lines
+    }
+
+    // The last line, even without its newline
+    fn take_partial(&mut self) -> Option<String> {
+        take_line(&mut self.partial)
+    }
+}
+
+fn take_line(partial: &mut Vec<u8>) -> Option<String> {
+    if partial.is_empty() {
+        return None;
+    }
+    let ln = String::from_utf8_lossy(partial)
+        .trim_end_matches(&['\n', '\r'][..])
+        .to_string();
+    partial.clear();
+    Some(ln)
+}
+
+// Only unix tells the files apart, a rotated file is noticed once it's truncated elsewhere
+#[cfg(unix)]
+fn file_id(metadata: &Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    metadata.ino()
+}
+
+#[cfg(not(unix))]
+fn file_id(_metadata: &Metadata) -> u64 {
+    0
+}
+
+// Offset of the last `lines` lines of a file like `tail -n`, read backwards block by block
+fn tail_offset(file: &mut File, lines: usize) -> std::io::Result<u64> {
+    let len = file.metadata()?.len();
+    if lines == 0 {
+        return Ok(len);
+    }
+    let mut block = vec![0; 8192];
+    let mut newlines = 0;
+    let mut end = len;
+    while end > 0 {
+        let start = end.saturating_sub(block.len() as u64);
+        let block = &mut block[..(end - start) as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(block)?;
+        for (i, byte) in block.iter().enumerate().rev() {
+            let offset = start + i as u64 + 1;
+            // The newline of the last line doesn't start another one
+            if *byte == b'\n' && offset != len {
+                newlines += 1;
+                if newlines == lines {
+                    return Ok(offset);
+                }
+            }
+        }
+        end = start;
+    }
+    Ok(0)
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("log")
+            .args(&[
+                Arg::new("file")
+                    .required(true)
+                    .multiple_values(true)
+                    .about("Log files to print and follow, e.g. /var/log/syslog or /var/log/nginx/access.log"),
+                Arg::new("format")
+                    .long("format")
+                    .takes_value(true)
+                    .possible_values(&["auto", "syslog", "combined", "error", "json", "logfmt"])
+                    .default_value("auto")
+                    .about(r#"Format of the files, "auto" detects it from the first lines of each file"#),
+                Arg::new("lines")
+                    .long("lines")
+                    .short('n')
+                    .takes_value(true)
+                    .default_value("10")
+                    .about(r#"Number of last lines printed before following, "all" prints the whole files"#),
+                Arg::new("no-follow")
+                    .long("no-follow")
+                    .about("Print the last lines and exit instead of following the files"),
+            ])
+            .about("Print and follow log files like `tail -F`, colored by their format (syslog, nginx/apache access and error logs, JSON lines and logfmt)")
+    }
+
+    pub fn parse(mut ctx: Context, app: &ArgMatches) -> i32 {
+        let format = match app.value_of("format") {
+            Some("auto") | None => None,
+            Some(format) => format.parse().ok(),
+        };
+        let lines = match app.value_of("lines") {
+            Some("all") => None,
+            Some(lines) => match lines.parse() {
+                Ok(lines) => Some(lines),
+                Err(_) => {
+                    println!(
+                        "Invalid number of lines \"{}\", use a number or \"all\"",
+                        lines
+                    );
+                    return 2;
+                }
+            },
+            None => Some(10),
+        };
+        let is_follow = !app.is_present("no-follow");
+        let mut files: Vec<Followed> = app
+            .values_of("file")
+            .into_iter()
+            .flatten()
+            .map(|path| Followed::new(path, format))
+            .collect();
+
+        let mut exit_code = 0;
+        for file in files.iter_mut() {
+            if let Err(e) = file.open(lines) {
+                // Waited for when following
+                eprintln!("ufc: {}: {}", file.path.display(), e);
+                exit_code = 1;
+            }
+        }
+
+        let output = ctx.clap_args.output;
+        if output == OutputFormat::Html {
+            print!("{}", html::header(&ctx.argv.join(" ")));
+        }
+        let user_palettes = config::user_palettes(&ctx.subcommand_name);
+        let stdout_bufwtr = BufferWriter::stdout(ColorChoice::Always);
+        let is_stdout_colored = is_colored(&ctx.clap_args, Stream::Stdout);
+        // Each file is filtered on its own, the colorizer of `ctx` changes with the format
+        let grep = ctx.clap_args.grep.clone().map(LazyRegex::from_string);
+        let mut filters: Vec<Filter> = files
+            .iter()
+            .map(|_| {
+                Filter::with(
+                    grep.as_ref(),
+                    ctx.clap_args.min_level,
+                    ctx.clap_args.context,
+                )
+            })
+            .collect();
+        let mut last = None;
+        loop {
+            let is_many = files.len() > 1;
+            for (index, file) in files.iter_mut().enumerate() {
+                let mut lines = file.poll();
+                if !is_follow {
+                    lines.extend(file.take_partial());
+                }
+                if lines.is_empty() {
+                    continue;
+                }
+                // Detected before the lines are filtered, from all of them
+                let format = *file
+                    .format
+                    .get_or_insert_with(|| Format::detect(lines.iter().map(String::as_str)));
+                let lines: Vec<Line> = lines
+                    .into_iter()
+                    .flat_map(|ln| filters[index].push(Line::new(ln)))
+                    .collect();
+                if lines.is_empty() {
+                    continue;
+                }
+                // "==> /var/log/nginx/error.log <==" before the lines of another file, like tail
+                if is_many && last != Some(index) {
+                    ctx.colorizer = Colorizer::new(vec![]);
+                    if last.is_some() {
+                        print_line(
+                            &ctx,
+                            &stdout_bufwtr,
+                            is_stdout_colored,
+                            String::new(),
+                            &Style::Default,
+                        );
+                    }
+                    print_line(
+                        &ctx,
+                        &stdout_bufwtr,
+                        is_stdout_colored,
+                        format!("==> {} <==", file.path.display()),
+                        &Style::BDefault,
+                    );
+                }
+                last = Some(index);
+
+                ctx.colorizer = Colorizer::new(
+                    ctx.highlights
+                        .iter()
+                        .chain(user_palettes.iter())
+                        .chain(format.palettes().iter())
+                        .collect(),
+                )
+                .with_thresholds(ctx.thresholds);
+                for line in lines {
+                    print_line(
+                        &ctx,
+                        &stdout_bufwtr,
+                        is_stdout_colored,
+                        line.text,
+                        line.style,
+                    );
+                }
+            }
+            // Followed until ctrl-c, the html footer is still printed
+            if !is_follow || ctx.interrupted.load(Ordering::SeqCst) {
+                break;
+            }
+            thread::sleep(Duration::from_millis(250));
+        }
+        if output == OutputFormat::Html {
+            println!("{}", html::footer());
+        }
+        exit_code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, io::Write};
+
+    use super::*;
+
+    #[test]
+    fn detect_formats() {
+        let detect = |sample: &str| Format::detect(sample.lines());
+        assert_eq!(
+            detect("Jun 15 10:01:12 host systemd[1]: Started Session 42 of user user.\nJun 15 10:01:13 host kernel: [ 5.1] eth0: link up"),
+            Format::Syslog
+        );
+        assert_eq!(
+            detect(
+                r#"203.0.113.7 - - [15/Jun/2021:10:01:12 +0800] "GET / HTTP/1.1" 200 612 "-" "curl/7.68.0""#
+            ),
+            Format::Combined
+        );
+        assert_eq!(
+            detect("[Tue Jun 15 10:01:12.123456 2021] [core:error] [pid 1234:tid 140] AH00126: Invalid URI"),
+            Format::Error
+        );
+        assert_eq!(
+            detect("2021/06/15 10:01:12 [error] 1234#1234: *5 open() failed"),
+            Format::Error
+        );
+        assert_eq!(
+            detect("\n{\"level\":\"info\",\"msg\":\"ok\"}\n{\"level\":\"warn\"}"),
+            Format::Json
+        );
+        assert_eq!(
+            detect(r#"time=2021-06-15T10:01:12Z level=info msg="Listening" addr=:8080"#),
+            Format::Logfmt
+        );
+        assert_eq!(detect("plain text"), Format::Syslog);
+    }
+
+    #[test]
+    fn follow_rotation_and_truncation() {
+        let dir = env::temp_dir().join(format!("ufc-log-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("app.log");
+        let append = |text: &str| {
+            fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .unwrap()
+                .write_all(text.as_bytes())
+                .unwrap()
+        };
+        append("1\n2\n3\n");
+
+        let mut file = Followed::new(path.to_str().unwrap(), None);
+        file.open(Some(2)).unwrap();
+        assert_eq!(file.poll(), ["2", "3"]);
+        // A line is printed once its newline is written
+        append("4\n5");
+        assert_eq!(file.poll(), ["4"]);
+        append("\n");
+        assert_eq!(file.poll(), ["5"]);
+
+        // logrotate moves the file away and the application writes to a new one
+        fs::rename(&path, dir.join("app.log.1")).unwrap();
+        assert_eq!(file.poll(), Vec::<String>::new());
+        append("6\n");
+        assert_eq!(file.poll(), ["6"]);
+
+        // copytruncate
+        fs::write(&path, "").unwrap();
+        assert_eq!(file.poll(), Vec::<String>::new());
+        append("7\n");
+        assert_eq!(file.poll(), ["7"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,18 +1,34 @@
 pub mod alias;
+pub mod app;
+pub mod arping;
 pub mod cli;
 pub mod completion;
+pub mod config;
 pub mod df;
 pub mod dig;
 pub mod docker;
 pub mod du;
 pub mod env;
 pub mod fdisk;
+pub mod filter;
 pub mod findmnt;
+pub mod fping;
 pub mod free;
+#[cfg(test)]
+mod golden;
+pub mod html;
 pub mod id;
 pub mod ifconfig;
 pub mod journalctl;
+pub mod json;
+pub mod log;
+pub mod nerdctl;
+pub mod nping;
+pub mod palette;
 pub mod ping;
+pub mod podman;
+pub mod registry;
+pub mod tcping;
 pub mod top;
 pub mod ualias;
-pub mod universal;
\ No newline at end of file
+pub mod universal;
@@ -0,0 +1,28 @@
+use clap::{App, AppSettings, Arg, ArgMatches};
+
+use super::{cli::Context, docker};
+
+// nerdctl (containerd) prints the same tables as docker, so it shares the palettes of `docker ps` and `docker images`
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("nerdctl")
+            .args(&[
+                Arg::new("address").long("address").short('a').takes_value(true).about(r#"containerd address, optionally with "unix://" prefix (default "/run/containerd/containerd.sock")"#),
+                Arg::new("cgroup-manager").long("cgroup-manager").takes_value(true).about(r#"Cgroup manager to use ("cgroupfs"|"systemd")"#),
+                Arg::new("debug").long("debug").about("debug mode"),
+                Arg::new("host").long("host").short('H').takes_value(true).about("Alias of --address"),
+                Arg::new("namespace").long("namespace").short('n').takes_value(true).about(r#"containerd namespace, such as "moby" for Docker, "k8s.io" for Kubernetes (default "default")"#),
+                Arg::new("snapshotter").long("snapshotter").takes_value(true).about(r#"containerd snapshotter (default "overlayfs")"#),
+                Arg::new("version").long("version").short('v').about("Print the version"),
+            ])
+            .setting(AppSettings::ArgRequiredElseHelp)
+            .subcommands(docker::Cmd::subcommands())
+            .about("nerdctl")
+    }
+
+    pub fn parse(ctx: Context, app: &ArgMatches) -> i32 {
+        docker::Cmd::parse(ctx, app)
+    }
+}
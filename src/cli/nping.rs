@@ -0,0 +1,86 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, AppSettings, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("nping")
+            .args(&[
+                Arg::new("targets")
+                    .multiple_values(true)
+                    .required(true)
+                    .about("Targets to probe, hostnames, IP addresses or networks"),
+                Arg::new("tcp-connect")
+                    .long("tcp-connect")
+                    .about("Unprivileged TCP connect probe mode."),
+                Arg::new("tcp").long("tcp").about("TCP probe mode."),
+                Arg::new("udp").long("udp").about("UDP probe mode."),
+                Arg::new("icmp").long("icmp").about("ICMP probe mode."),
+                Arg::new("arp").long("arp").about("ARP/RARP probe mode."),
+                Arg::new("traceroute")
+                    .long("traceroute")
+                    .about("Traceroute mode (can only be used with TCP/UDP/ICMP modes)."),
+                Arg::new("dest-port")
+                    .long("dest-port")
+                    .short('p')
+                    .takes_value(true)
+                    .about("Set destination port(s)."),
+                Arg::new("source-port")
+                    .long("source-port")
+                    .short('g')
+                    .takes_value(true)
+                    .about("Set source port."),
+                Arg::new("flags")
+                    .long("flags")
+                    .takes_value(true)
+                    .about("Set TCP flags (ACK,PSH,RST,SYN,FIN...)"),
+                Arg::new("count")
+                    .long("count")
+                    .short('c')
+                    .takes_value(true)
+                    .about("Stop after <n> rounds."),
+                Arg::new("delay")
+                    .long("delay")
+                    .takes_value(true)
+                    .about("Adjust delay between probes."),
+                Arg::new("rate")
+                    .long("rate")
+                    .takes_value(true)
+                    .about("Send num packets per second."),
+                Arg::new("ttl")
+                    .long("ttl")
+                    .takes_value(true)
+                    .about("Set time to live [0-255]."),
+                Arg::new("data-length")
+                    .long("data-length")
+                    .takes_value(true)
+                    .about("Append random data to the packets."),
+                Arg::new("verbose")
+                    .short('v')
+                    .multiple_occurrences(true)
+                    .about("Increment verbosity level by one."),
+                Arg::new("quiet")
+                    .short('q')
+                    .multiple_occurrences(true)
+                    .about("Decrease verbosity level by one."),
+                Arg::new("hide-sent")
+                    .short('H')
+                    .long("hide-sent")
+                    .about("Do not display sent packets."),
+                Arg::new("version")
+                    .long("version")
+                    .short('V')
+                    .about("Display current version number."),
+            ])
+            .setting(AppSettings::ArgRequiredElseHelp)
+            .about("nping")
+    }
+
+    pub fn parse(ctx: Context, _app: &ArgMatches) -> i32 {
+        pre_exec(ctx, registry::palettes("nping"))
+    }
+}
@@ -0,0 +1,364 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufReader, Write},
+    path::Path,
+};
+
+use atty::Stream;
+use clap::{App, AppSettings, Arg, ArgMatches};
+use termcolor::{Buffer, BufferWriter, ColorChoice, ColorSpec, WriteColor};
+
+use crate::cli::{
+    cli::{is_colored, print_lines, Context},
+    config,
+    registry::{self, Profile},
+};
+use ufc::{get_color, Colorizer, Palette};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("palette")
+            .subcommands(vec![
+                App::new("list")
+                    .args(&[
+                        Arg::new("profile").multiple_values(true).about(
+                            r#"Profile to list, e.g. "df" or "docker ps". All profiles are listed if omitted"#,
+                        ),
+                        Arg::new("samples")
+                            .long("samples")
+                            .takes_value(true)
+                            .default_value("tests/golden")
+                            .about("Directory of captured outputs to take a sample line of each rule from"),
+                    ])
+                    .about("List the rules of a profile with their regex, colors and a sample line they color"),
+                App::new("test")
+                    .args(&[
+                        Arg::new("profile")
+                            .required(true)
+                            .multiple_values(true)
+                            .about(r#"Profile to test, e.g. "df" or "docker ps""#),
+                        Arg::new("file")
+                            .required(true)
+                            .about(r#"Sample output to colorize, "-" reads from stdin"#),
+                    ])
+                    .about("Colorize a sample output with a profile without running the subcommand, use it with --explain to see which rule fired"),
+                App::new("lint")
+                    .args(&[
+                        Arg::new("profile").multiple_values(true).about(
+                            r#"Profile to lint, e.g. "df" or "docker ps". All profiles are linted if omitted"#,
+                        ),
+                        Arg::new("samples")
+                            .long("samples")
+                            .takes_value(true)
+                            .default_value("tests/golden")
+                            .about("Directory of captured outputs, <samples>/<profile>/*.txt with \"docker ps\" as \"docker-ps\""),
+                    ])
+                    .about("Check the rules of a profile for invalid regexes, unused colors, identical regexes, and rules which never color the samples. A rule shadowed by a broader earlier one is only found through the samples"),
+            ])
+            .setting(AppSettings::SubcommandRequiredElseHelp)
+            .about("Inspect the built-in and user-defined palettes")
+    }
+
+    pub fn parse(ctx: Context, app: &ArgMatches) -> i32 {
+        match app.subcommand() {
+            Some(("list", args)) => Cmd::list(&ctx, args),
+            Some(("test", args)) => Cmd::test(ctx, args),
+            Some(("lint", args)) => Cmd::lint(args),
+            _ => 0,
+        }
+    }
+
+    fn test(ctx: Context, args: &ArgMatches) -> i32 {
+        let name = args
+            .values_of("profile")
+            .unwrap()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let profile = match registry::find(&name) {
+            Some(profile) => profile,
+            None => {
+                println!(
+                    "Unsupported profile \"{}\", try `ufc palette list` to get all profiles",
+                    name
+                );
+                return 1;
+            }
+        };
+        let user_palettes = config::user_palettes(name.split(' ').next().unwrap_or_default());
+        let palettes = user_palettes
+            .iter()
+            .chain(profile.palettes.iter())
+            .collect();
+
+        match args.value_of("file").unwrap() {
+            "-" => print_lines(ctx, palettes, io::stdin().lock()),
+            path => match File::open(path) {
+                Ok(file) => print_lines(ctx, palettes, BufReader::new(file)),
+                Err(e) => {
+                    eprintln!("ufc: {}: {}", path, e);
+                    return 1;
+                }
+            },
+        }
+        0
+    }
+
+    fn lint(args: &ArgMatches) -> i32 {
+        let profiles = match args.values_of("profile") {
+            Some(values) => {
+                let name = values.collect::<Vec<_>>().join(" ");
+                match registry::find(&name) {
+                    Some(profile) => vec![profile],
+                    None => {
+                        println!(
+                            "Unsupported profile \"{}\", try `ufc palette list` to get all profiles",
+                            name
+                        );
+                        return 1;
+                    }
+                }
+            }
+            None => registry::profiles().collect(),
+        };
+        let samples = Path::new(args.value_of("samples").unwrap());
+
+        let problems: usize = profiles
+            .into_iter()
+            .map(|profile| lint_profile(&profile, samples))
+            .sum();
+        if problems > 0 {
+            println!("{} problem(s) found", problems);
+            return 1;
+        }
+        0
+    }
+
+    fn list(ctx: &Context, args: &ArgMatches) -> i32 {
+        let name = match args.values_of("profile") {
+            Some(values) => values.collect::<Vec<_>>().join(" "),
+            None => {
+                for profile in registry::profiles() {
+                    println!("{}", profile.name);
+                }
+                return 0;
+            }
+        };
+        let profile = match registry::find(&name) {
+            Some(profile) => profile,
+            None => {
+                println!(
+                    "Unsupported profile \"{}\", try `ufc palette list` to get all profiles",
+                    name
+                );
+                return 1;
+            }
+        };
+
+        // Same order as they are applied, so the index matches the "rule" of `--output=json`
+        let user_palettes = config::user_palettes(name.split(' ').next().unwrap_or_default());
+        let palettes = user_palettes.iter().chain(profile.palettes.iter());
+        let samples: Vec<String> =
+            registry::samples(Path::new(args.value_of("samples").unwrap()), &name)
+                .iter()
+                .filter_map(|path| fs::read_to_string(path).ok())
+                .collect();
+        let lines: Vec<&str> = samples.iter().flat_map(|sample| sample.lines()).collect();
+
+        let choice = if is_colored(&ctx.clap_args, Stream::Stdout) {
+            ColorChoice::Always
+        } else {
+            ColorChoice::Never
+        };
+        let bufwtr = BufferWriter::stdout(choice);
+        let mut buffer = bufwtr.buffer();
+        for (index, palette) in palettes.enumerate() {
+            print_rule(&mut buffer, index, palette, &lines);
+        }
+        bufwtr.print(&buffer).unwrap();
+        0
+    }
+}
+
+fn print_rule(buffer: &mut Buffer, index: usize, palette: &Palette, lines: &[&str]) {
+    buffer.set_color(ColorSpec::new().set_bold(true)).unwrap();
+    write!(buffer, "{:>3} {}", index, palette.name).unwrap();
+    buffer.reset().unwrap();
+    writeln!(buffer).unwrap();
+    writeln!(buffer, "      {}", palette.regexp.as_str()).unwrap();
+    write!(buffer, "     ").unwrap();
+    for color in palette.colors.iter() {
+        write!(buffer, " ").unwrap();
+        buffer.set_color(&get_color(color)).unwrap();
+        write!(buffer, "{}", color.name()).unwrap();
+        buffer.reset().unwrap();
+    }
+    writeln!(buffer).unwrap();
+
+    // The shortest line of the samples which the rule colors, colored by this rule alone
+    let colorizer = Colorizer::new(vec![palette]);
+    let sample = lines
+        .iter()
+        .map(|line| (line, colorizer.colorize_line(line)))
+        .filter(|(_, spans)| !spans.is_empty())
+        .min_by_key(|(line, _)| line.len());
+    if let Some((line, spans)) = sample {
+        write!(buffer, "      ").unwrap();
+        let mut start = 0;
+        for span in spans {
+            write!(buffer, "{}", &line[start..span.start]).unwrap();
+            buffer.set_color(&get_color(span.style)).unwrap();
+            write!(buffer, "{}", &line[span.start..span.end]).unwrap();
+            buffer.reset().unwrap();
+            start = span.end;
+        }
+        writeln!(buffer, "{}", &line[start..]).unwrap();
+    }
+}
+
+// Print the problems of every rule of a profile (user-defined ones first, as they are applied)
+// and return how many were found
+fn lint_profile(profile: &Profile, samples: &Path) -> usize {
+    let (user_palettes, errors) =
+        config::check_user_palettes(profile.name.split(' ').next().unwrap_or_default());
+    let palettes: Vec<&'static Palette<'static>> = user_palettes
+        .iter()
+        .chain(profile.palettes.iter())
+        .collect();
+
+    // Rules of the user-defined palettes which can't even be loaded
+    for e in errors.iter() {
+        println!("{}: {}", profile.name, e);
+    }
+
+    let mut problems: Vec<(usize, String)> = vec![];
+    for (index, palette) in palettes.iter().enumerate() {
+        match palette.regexp.compile() {
+            Ok(regex) => {
+                // colors[0] colors the full match, colors[i] the i-th group
+                let groups = regex.captures_len() - 1;
+                let colors = palette.colors.len();
+                if palette.is_gauge() {
+                    // The colors of a gauge are its levels
+                } else if colors > groups + 1 {
+                    problems.push((
+                        index,
+                        format!(
+                            "{} colors for {} capture group(s), the last {} are never used",
+                            colors,
+                            groups,
+                            colors - groups - 1
+                        ),
+                    ));
+                } else if colors > 1 && colors < groups + 1 {
+                    problems.push((
+                        index,
+                        format!(
+                            "{} colors for {} capture group(s), the last {} group(s) use the first color",
+                            colors,
+                            groups,
+                            groups + 1 - colors
+                        ),
+                    ));
+                }
+            }
+            Err(e) => {
+                problems.push((index, format!("invalid regex: {}", e)));
+            }
+        }
+        // Only an identical regex is caught here, a broader earlier rule (`\d+` before
+        // `(?P<percent>\d+)%`) shows up below when it takes every match of the samples
+        if let Some(earlier) = palettes[..index]
+            .iter()
+            .position(|earlier| earlier.regexp.as_str() == palette.regexp.as_str())
+        {
+            problems.push((index, format!("duplicate regex of rule {}", earlier)));
+        }
+    }
+
+    // Run the samples through the colorizer to find the rules which never color anything,
+    // an invalid regex matches nothing there and is only reported above
+    let paths = registry::samples(samples, profile.name);
+    if !paths.is_empty() {
+        let mut is_matched = vec![false; palettes.len()];
+        let mut is_fired = vec![false; palettes.len()];
+        let colorizer = Colorizer::new(palettes.clone());
+        for path in paths.iter() {
+            let sample = match fs::read_to_string(path) {
+                Ok(sample) => sample,
+                Err(e) => {
+                    eprintln!("ufc: {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+            for line in sample.lines() {
+                for (index, palette) in palettes.iter().enumerate() {
+                    if palette.regexp.is_match(line).unwrap_or(false) {
+                        is_matched[index] = true;
+                    }
+                }
+                for span in colorizer.colorize_line(line) {
+                    is_fired[span.rule] = true;
+                }
+            }
+        }
+        let dir = samples.join(profile.name.replace(' ', "-"));
+        for index in 0..palettes.len() {
+            if is_fired[index] || palettes[index].regexp.compile().is_err() {
+                continue;
+            }
+            let problem = if is_matched[index] {
+                format!(
+                    "matches the samples in {} but earlier rules always color it first, it may be shadowed",
+                    dir.display()
+                )
+            } else {
+                format!("never matches the samples in {}", dir.display())
+            };
+            problems.push((index, problem));
+        }
+    }
+
+    problems.sort_by_key(|(index, _)| *index);
+    for (index, problem) in problems.iter() {
+        println!(
+            "{}: rule {} \"{}\": {}",
+            profile.name, index, palettes[*index].name, problem
+        );
+    }
+    errors.len() + problems.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ufc::{LazyRegex, Style};
+
+    #[test]
+    fn rule_with_sample() {
+        let palette = Palette {
+            name: "Use%",
+            regexp: LazyRegex::new(r#"\d+%"#),
+            colors: vec![&Style::Red],
+        };
+        let print = |lines: &[&str]| {
+            let mut buffer = Buffer::ansi();
+            print_rule(&mut buffer, 2, &palette, lines);
+            String::from_utf8(buffer.into_inner()).unwrap()
+        };
+        let header =
+            "\x1b[0m\x1b[1m  2 Use%\x1b[0m\n      \\d+%\n      \x1b[0m\x1b[31mRed\x1b[0m\n";
+        // The shortest line the rule colors
+        assert_eq!(
+            print(&[
+                "Filesystem Use% Mounted on",
+                "/dev/sda1 79% /",
+                "tmpfs 0% /run"
+            ]),
+            format!("{}      tmpfs \x1b[0m\x1b[31m0%\x1b[0m /run\n", header)
+        );
+        assert_eq!(print(&["Filesystem Use%"]), header);
+        assert_eq!(print(&[]), header);
+    }
+}
@@ -1,6 +1,17 @@
-use crate::cli::cli::{pre_exec, Colors, Palette};
+use std::sync::Mutex;
+
+use crate::cli::{
+    cli::{pre_exec, Context, Line, Rewrite},
+    registry,
+};
 use clap::{App, AppSettings, Arg, ArgMatches};
-use fancy_regex::Regex;
+use ufc::{LazyRegex, Style};
+
+// "icmp_seq=3" of iputils and macOS, "seq=3" of busybox, "Request timeout for icmp_seq 3"
+static SEQ: LazyRegex = LazyRegex::new(r#"\b(?:icmp_)?seq[= ](\d+)\b"#);
+static TIME: LazyRegex = LazyRegex::new(r#"\btime[=<]([\d.]+) ?ms"#);
+static HOST: LazyRegex = LazyRegex::new(r#"^PING (\S+)"#);
+static STATISTICS: LazyRegex = LazyRegex::new(r#"^--- \S+ (?:ping )?statistics ---"#);
 
 pub struct Cmd {}
 
@@ -49,101 +60,200 @@ impl Cmd {
             .about("ping")
     }
 
-    pub fn parse(_app: &ArgMatches) {
-        // print!("{:?}", app);
-        pre_exec(Cmd::palette());
+    // ping6 of older iputils and BSD, the same options and output as `ping -6`
+    pub fn new6() -> App<'static> {
+        Cmd::new().name("ping6").about("ping6")
+    }
+
+    pub fn parse(mut ctx: Context, _app: &ArgMatches) -> i32 {
+        ctx.rewriter = Some(Mutex::new(Box::new(Replies::default())));
+        pre_exec(ctx, registry::palettes("ping"))
+    }
+}
+
+// Follows the replies of ping: a missing icmp_seq is printed as lost as soon as the next reply
+// comes, and an interrupted ping gets a summary with the jitter, which not every ping prints
+#[derive(Debug, Default)]
+pub struct Replies {
+    host: Option<String>,
+    next_seq: Option<u64>,
+    sent: u64,
+    received: u64,
+    // Round-trip times in ms, the jitter is the mean difference between consecutive ones
+    min: f64,
+    max: f64,
+    sum: f64,
+    last: Option<f64>,
+    jitter_sum: f64,
+    // The ping printed its own statistics, e.g. iputils on SIGINT
+    has_statistics: bool,
+}
+
+impl Replies {
+    fn reply(&mut self, seq: u64, time: Option<f64>) -> Option<Line> {
+        // A ping restarted by --watch or a wrapped icmp_seq starts over
+        let lost = match self.next_seq {
+            Some(next_seq) if seq > next_seq => seq - next_seq,
+            _ => 0,
+        };
+        self.next_seq = Some(seq + 1);
+        self.sent += lost + 1;
+        if let Some(time) = time {
+            if self.received == 0 {
+                self.min = time;
+                self.max = time;
+            }
+            self.received += 1;
+            self.min = self.min.min(time);
+            self.max = self.max.max(time);
+            self.sum += time;
+            if let Some(last) = self.last {
+                self.jitter_sum += (time - last).abs();
+            }
+            self.last = Some(time);
+        }
+        let text = match lost {
+            0 => return None,
+            1 => format!("icmp_seq={} lost", seq - 1),
+            _ => format!(
+                "icmp_seq={}-{} lost ({} packets)",
+                seq - lost,
+                seq - 1,
+                lost
+            ),
+        };
+        Some(Line {
+            text,
+            style: &Style::Red,
+            level: None,
+        })
     }
 
-    fn palette() -> Vec<Palette<'static>> {
-        vec![
-            // nping
-            Palette {
-                regexp: Regex::new(r#"unreachable"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            // these are good for nping
-            Palette {
-                regexp: Regex::new(r#"SENT|RCVD"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            // last line values
-            Palette {
-                regexp: Regex::new(r#"\=\s([0-9\.]+)/([0-9\.]+)/([0-9\.]+)/([0-9\.]+)"#).unwrap(),
-                colors: vec![
-                    &Colors::Default,
-                    &Colors::BYellow,
-                    &Colors::BBlue,
-                    &Colors::BRed,
-                    &Colors::BMagenta,
-                ],
-            },
-            // last line min/avg/max/mdev
-            Palette {
-                regexp: Regex::new(r#"rtt (min)/(avg)/(max)/(mdev)"#).unwrap(),
-                colors: vec![
-                    &Colors::Default,
-                    &Colors::BYellow,
-                    &Colors::BBlue,
-                    &Colors::BRed,
-                    &Colors::BMagenta,
-                ],
-            },
-            // statistics header
-            Palette {
-                regexp: Regex::new(r#"--- (\S+) ping statistics ---"#).unwrap(),
-                colors: vec![&Colors::BDefault, &Colors::BBlue],
-            },
-            // unknown host
-            Palette {
-                regexp: Regex::new(r#".+unknown\shost\s(.+)"#).unwrap(),
-                colors: vec![&Colors::Red, &Colors::BRed],
-            },
-            // Errors
-            Palette {
-                regexp: Regex::new(r#"(Destination Host Unreachable|100(\.0)?% packet loss)"#)
-                    .unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            // OK
-            Palette {
-                regexp: Regex::new(r#" 0(\.0)?% packet loss"#).unwrap(),
-                colors: vec![&Colors::Green],
-            },
-            // DUP
-            Palette {
-                regexp: Regex::new(r#"DUP\!"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            // time
-            Palette {
-                regexp: Regex::new(r#"([0-9\.]+)?\s?ms"#).unwrap(),
-                colors: vec![&Colors::Green, &Colors::BGreen],
-            },
-            // name
-            Palette {
-                regexp: Regex::new(r#"(?:[fF]rom|PING)\s(\S+)\s"#).unwrap(),
-                colors: vec![&Colors::Default, &Colors::Blue],
-            },
-            // ttl=
-            Palette {
-                regexp: Regex::new(r#"ttl=(\d+)"#).unwrap(),
-                colors: vec![&Colors::Default, &Colors::Magenta],
-            },
-            // icmp_seq=
-            Palette {
-                regexp: Regex::new(r#"icmp_seq=(\d+)"#).unwrap(),
-                colors: vec![&Colors::Default, &Colors::Magenta],
-            },
-            // ipv6 number
-            Palette {
-                regexp: Regex::new(r#"(([0-9a-fA-F]{1,4})?\:\:?[0-9a-fA-F]{1,4})+"#).unwrap(),
-                colors: vec![&Colors::Magenta],
-            },
-            // IP
-            Palette {
-                regexp: Regex::new(r#"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}"#).unwrap(),
-                colors: vec![&Colors::BBlue],
-            },
-        ]
+    fn summary(&self) -> Vec<Line> {
+        let loss = (self.sent - self.received) as f64 * 100.0 / self.sent as f64;
+        let mut lines = vec![
+            Line::new(String::new()),
+            Line::new(format!(
+                "--- {} ping statistics ---",
+                self.host.as_deref().unwrap_or("ufc")
+            )),
+            Line::new(format!(
+                "{} packets transmitted, {} received, {}% packet loss",
+                self.sent,
+                self.received,
+                (loss * 10.0).round() / 10.0
+            )),
+        ];
+        if self.received > 0 {
+            let jitter = match self.received {
+                1 => 0.0,
+                received => self.jitter_sum / (received - 1) as f64,
+            };
+            lines.push(Line::new(format!(
+                "rtt min/avg/max/jitter = {:.3}/{:.3}/{:.3}/{:.3} ms",
+                self.min,
+                self.sum / self.received as f64,
+                self.max,
+                jitter
+            )));
+        }
+        lines
+    }
+}
+
+impl Rewrite for Replies {
+    fn rewrite(&mut self, ln: String) -> Vec<Line> {
+        if STATISTICS.is_match(&ln).unwrap_or(false) {
+            self.has_statistics = true;
+        }
+        let captures = |regex: &LazyRegex| {
+            regex
+                .captures(&ln)
+                .ok()
+                .flatten()
+                .and_then(|captures| captures.get(1).map(|group| group.as_str().to_string()))
+        };
+        if let Some(host) = captures(&HOST) {
+            self.host = Some(host);
+        }
+        let seq = captures(&SEQ).and_then(|seq| seq.parse().ok());
+        let mut lines = vec![];
+        // A duplicate is neither sent nor lost
+        if let (Some(seq), false) = (seq, ln.contains("DUP!")) {
+            let time = captures(&TIME).and_then(|time| time.parse().ok());
+            lines.extend(self.reply(seq, time));
+        }
+        lines.push(Line::new(ln));
+        lines
+    }
+
+    // A summary only if ping was interrupted before it could print its own
+    fn finish(&mut self, interrupted: bool) -> Vec<Line> {
+        let lines = match (interrupted, self.has_statistics, self.sent) {
+            (true, false, sent) if sent > 0 => self.summary(),
+            _ => vec![],
+        };
+        *self = Self::default();
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lost_replies_and_summary() {
+        let mut replies = Replies::default();
+        let output = "PING example.com (93.184.216.34) 56(84) bytes of data.
+64 bytes from 93.184.216.34: icmp_seq=1 ttl=56 time=10.0 ms
+64 bytes from 93.184.216.34: icmp_seq=2 ttl=56 time=14.0 ms
+64 bytes from 93.184.216.34: icmp_seq=2 ttl=56 time=15.0 ms (DUP!)
+64 bytes from 93.184.216.34: icmp_seq=5 ttl=56 time=12.0 ms
+64 bytes from 93.184.216.34: icmp_seq=6 ttl=56 time=20.0 ms";
+        let lost: Vec<String> = output
+            .lines()
+            .flat_map(|ln| replies.rewrite(ln.to_string()))
+            .filter(|line| line.style == &Style::Red)
+            .map(|line| line.text)
+            .collect();
+        assert_eq!(lost, ["icmp_seq=3-4 lost (2 packets)"]);
+
+        let summary: Vec<String> = replies
+            .summary()
+            .into_iter()
+            .map(|line| line.text)
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                "",
+                "--- example.com ping statistics ---",
+                "6 packets transmitted, 4 received, 33.3% packet loss",
+                "rtt min/avg/max/jitter = 10.000/14.000/20.000/4.667 ms",
+            ]
+        );
+        assert_eq!(replies.finish(true).len(), 4);
+    }
+
+    #[test]
+    fn no_summary_after_the_statistics_of_ping() {
+        let output = "PING example.com (93.184.216.34) 56(84) bytes of data.
+64 bytes from 93.184.216.34: icmp_seq=1 ttl=56 time=10.0 ms
+64 bytes from 93.184.216.34: icmp_seq=2 ttl=56 time=14.0 ms
+
+--- example.com ping statistics ---
+2 packets transmitted, 2 received, 0% packet loss, time 1001ms
+rtt min/avg/max/mdev = 10.000/12.000/14.000/2.000 ms";
+        let mut replies = Replies::default();
+        output.lines().for_each(|ln| {
+            replies.rewrite(ln.to_string());
+        });
+        assert!(replies.finish(true).is_empty());
+
+        // Nor once the ping ended by itself
+        let mut replies = Replies::default();
+        replies.rewrite(output.lines().nth(1).unwrap().to_string());
+        assert!(replies.finish(false).is_empty());
     }
 }
@@ -0,0 +1,29 @@
+use clap::{App, AppSettings, Arg, ArgMatches};
+
+use super::{cli::Context, docker};
+
+// podman prints the same tables as docker, so it shares the palettes of `docker ps` and `docker images`
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("podman")
+            .args(&[
+                Arg::new("connection").long("connection").short('c').takes_value(true).about("Connection to use for remote Podman service"),
+                Arg::new("log-level").long("log-level").takes_value(true).about(r#"Log messages above specified level ("debug"|"info"|"warn"|"error"|"fatal"|"panic") (default "warn")"#),
+                Arg::new("remote").long("remote").short('r').about("Access remote Podman service"),
+                Arg::new("root").long("root").takes_value(true).about("Path to the root directory in which data, including images, is stored"),
+                Arg::new("runroot").long("runroot").takes_value(true).about("Path to the 'run directory' where all state information is stored"),
+                Arg::new("storage-driver").long("storage-driver").takes_value(true).about("Select which storage driver is used to manage storage of images and containers"),
+                Arg::new("url").long("url").takes_value(true).about("URL to access Podman service"),
+                Arg::new("version").long("version").short('v').about("Version of Podman"),
+            ])
+            .setting(AppSettings::ArgRequiredElseHelp)
+            .subcommands(docker::Cmd::subcommands())
+            .about("podman")
+    }
+
+    pub fn parse(ctx: Context, app: &ArgMatches) -> i32 {
+        docker::Cmd::parse(ctx, app)
+    }
+}
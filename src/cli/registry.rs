@@ -0,0 +1,517 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use clap::{App, AppSettings, Arg, ArgMatches};
+
+use crate::cli::{
+    arping,
+    cli::{pre_exec, Context},
+    df, dig, docker, du, env, fdisk, findmnt, fping, free, id, ifconfig, journalctl, nerdctl,
+    nping, ping, podman, tcping, top,
+};
+use ufc::{LazyRegex, Palette};
+
+// Every built-in subcommand wrapping a command, drives the dispatch in `main`,
+// the completion (through the clap `App`s) and `ufc alias`
+pub struct Command {
+    pub name: &'static str,
+    // First word of the profiles used by this command, e.g. "docker" for podman
+    pub profile: &'static str,
+    pub app: fn() -> App<'static>,
+    // Runs the command and returns its exit code
+    pub parse: fn(Context, &ArgMatches) -> i32,
+}
+
+pub const COMMANDS: &[Command] = &[
+    Command {
+        name: "arping",
+        profile: "arping",
+        app: arping::Cmd::new,
+        parse: arping::Cmd::parse,
+    },
+    Command {
+        name: "df",
+        profile: "df",
+        app: df::Cmd::new,
+        parse: df::Cmd::parse,
+    },
+    Command {
+        name: "dig",
+        profile: "dig",
+        app: dig::Cmd::new,
+        parse: dig::Cmd::parse,
+    },
+    Command {
+        name: "docker",
+        profile: "docker",
+        app: docker::Cmd::new,
+        parse: docker::Cmd::parse,
+    },
+    Command {
+        name: "du",
+        profile: "du",
+        app: du::Cmd::new,
+        parse: du::Cmd::parse,
+    },
+    Command {
+        name: "env",
+        profile: "env",
+        app: env::Cmd::new,
+        parse: env::Cmd::parse,
+    },
+    Command {
+        name: "fdisk",
+        profile: "fdisk",
+        app: fdisk::Cmd::new,
+        parse: fdisk::Cmd::parse,
+    },
+    Command {
+        name: "findmnt",
+        profile: "findmnt",
+        app: findmnt::Cmd::new,
+        parse: findmnt::Cmd::parse,
+    },
+    Command {
+        name: "fping",
+        profile: "fping",
+        app: fping::Cmd::new,
+        parse: fping::Cmd::parse,
+    },
+    Command {
+        name: "free",
+        profile: "free",
+        app: free::Cmd::new,
+        parse: free::Cmd::parse,
+    },
+    Command {
+        name: "id",
+        profile: "id",
+        app: id::Cmd::new,
+        parse: id::Cmd::parse,
+    },
+    Command {
+        name: "ifconfig",
+        profile: "ifconfig",
+        app: ifconfig::Cmd::new,
+        parse: ifconfig::Cmd::parse,
+    },
+    Command {
+        name: "journalctl",
+        profile: "journalctl",
+        app: journalctl::Cmd::new,
+        parse: journalctl::Cmd::parse,
+    },
+    Command {
+        name: "nerdctl",
+        profile: "docker",
+        app: nerdctl::Cmd::new,
+        parse: nerdctl::Cmd::parse,
+    },
+    Command {
+        name: "nping",
+        profile: "nping",
+        app: nping::Cmd::new,
+        parse: nping::Cmd::parse,
+    },
+    Command {
+        name: "ping",
+        profile: "ping",
+        app: ping::Cmd::new,
+        parse: ping::Cmd::parse,
+    },
+    Command {
+        name: "ping6",
+        profile: "ping",
+        app: ping::Cmd::new6,
+        parse: ping::Cmd::parse,
+    },
+    Command {
+        name: "podman",
+        profile: "docker",
+        app: podman::Cmd::new,
+        parse: podman::Cmd::parse,
+    },
+    Command {
+        name: "tcping",
+        profile: "tcping",
+        app: tcping::Cmd::new,
+        parse: tcping::Cmd::parse,
+    },
+    Command {
+        name: "top",
+        profile: "top",
+        app: top::Cmd::new,
+        parse: top::Cmd::parse,
+    },
+];
+
+pub fn command(name: &str) -> Option<&'static Command> {
+    COMMANDS.iter().find(|command| command.name == name)
+}
+
+// A user-defined command colored as another one, see `config::user_mappings`
+#[derive(Debug)]
+pub struct Mapping {
+    pub pattern: Pattern,
+    // Replaces the command name, e.g. "docker" for "podman" so "podman ps" is colored as "docker ps"
+    pub target: String,
+}
+
+#[derive(Debug)]
+pub enum Pattern {
+    // Command name, e.g. "podman"
+    Name(String),
+    // Regex over the whole command line, e.g. "^ip -s link"
+    Regex(LazyRegex),
+}
+
+impl Mapping {
+    // A subcommand of ufc so the mapped command gets completed and passes its args through untouched
+    pub fn app(&self) -> Option<App<'static>> {
+        match &self.pattern {
+            Pattern::Name(name) if command(name).is_none() => Some(
+                App::new(name.as_str())
+                    .setting(AppSettings::TrailingVarArg)
+                    .arg(
+                        Arg::new("args")
+                            .multiple_values(true)
+                            .allow_hyphen_values(true),
+                    )
+                    .about(&*Box::leak(
+                        format!(r#"Colorized as "{}""#, self.target).into_boxed_str(),
+                    )),
+            ),
+            _ => None,
+        }
+    }
+}
+
+// Every command name `ufc alias` should wrap, the built-in ones and the user-defined ones
+pub fn command_names(mappings: &[Mapping]) -> Vec<&str> {
+    let mut names: Vec<&str> = COMMANDS.iter().map(|command| command.name).collect();
+    for mapping in mappings.iter() {
+        if let Pattern::Name(name) = &mapping.pattern {
+            if !names.contains(&name.as_str()) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+// A built-in palette set, see `ufc::profiles`
+#[derive(Debug, Clone, Copy)]
+pub struct Profile {
+    pub name: &'static str,
+    pub palettes: &'static [Palette<'static>],
+}
+
+// The built-in palette sets, the palettes of each one are built when it's looked up
+pub fn profiles() -> impl Iterator<Item = Profile> {
+    ufc::profiles().map(|name| Profile {
+        name,
+        palettes: palettes(name),
+    })
+}
+
+// The palettes of a built-in profile, none for an unknown name
+pub fn palettes(name: &str) -> &'static [Palette<'static>] {
+    ufc::palettes(name).unwrap_or_default()
+}
+
+impl Profile {
+    // Run the command line of `ctx` with this profile, like `ufc <command>` when the detected
+    // command (through its wrappers) is a built-in one of the profile, e.g. "sudo df -i" gets
+    // the palettes of -i and "ssh host ping6 x" the lost replies of ping, otherwise with the
+    // palettes of the profile, e.g. a user-defined "lsblk" colored as findmnt
+    pub fn run(&self, mut ctx: Context) -> i32 {
+        match self.command(&ctx) {
+            Some((command, matches)) => {
+                ctx.subcommand_name = command.name.to_string();
+                (command.parse)(ctx, &matches)
+            }
+            None => self.exec(ctx),
+        }
+    }
+
+    // The built-in command which runs the command line of `ctx`, never for a `--profile` as its
+    // palettes are forced, e.g. `--profile "df inodes" df` isn't colored by the sizes of df
+    fn command(&self, ctx: &Context) -> Option<(&'static Command, ArgMatches)> {
+        match ctx.clap_args.profile {
+            Some(_) => None,
+            None => self.parse(effective_argv(&ctx.argv)),
+        }
+    }
+
+    // The built-in command of this profile which `argv` runs and its args as parsed by the `App`
+    // of the command, `None` if it's another command or the `App` rejects them
+    fn parse(&self, argv: &[String]) -> Option<(&'static Command, ArgMatches)> {
+        let command = command(basename(argv.first()?))
+            .filter(|command| self.name.split(' ').next() == Some(command.profile))?;
+        let matches = (command.app)().try_get_matches_from(argv).ok()?;
+        Some((command, matches))
+    }
+
+    // Wrap the command line of `ctx` with the palettes of this profile, whatever the command is
+    pub fn exec(&self, mut ctx: Context) -> i32 {
+        // User-defined palettes follow the profile, e.g. "sudo df" loads `df.palette`
+        ctx.subcommand_name = self.name.split(' ').next().unwrap_or_default().to_string();
+        pre_exec(ctx, self.palettes)
+    }
+}
+
+pub fn find(name: &str) -> Option<Profile> {
+    profiles().find(|profile| profile.name == name)
+}
+
+// Find the profile of a command line which isn't a subcommand of ufc,
+// e.g. "sudo -u root /usr/sbin/fdisk -l" -> "fdisk" or "ssh host docker ps" -> "docker ps",
+// the first mapping which matches replaces the command name, e.g. "podman ps" -> "docker ps"
+pub fn detect(argv: &[String], mappings: &[Mapping]) -> Option<Profile> {
+    let mut words: Vec<&str> = effective_argv(argv)
+        .iter()
+        .flat_map(|arg| arg.split_whitespace())
+        .collect();
+    let name = basename(words.first()?);
+    words[0] = name;
+    let line = words.join(" ");
+    if let Some(mapping) = mappings.iter().find(|mapping| match &mapping.pattern {
+        Pattern::Name(name) => name == words[0],
+        Pattern::Regex(regex) => regex.is_match(&line).unwrap_or(false),
+    }) {
+        words.splice(..1, mapping.target.split_whitespace());
+    }
+    // e.g. "podman ps" -> "docker ps"
+    if let Some(command) = command(words[0]) {
+        words[0] = command.profile;
+    }
+    words
+        .get(1)
+        .and_then(|word| find(&format!("{} {}", words[0], word)))
+        .or_else(|| find(words[0]))
+}
+
+// Look through the wrappers to the command which actually prints the output
+pub fn effective_argv(argv: &[String]) -> &[String] {
+    let mut argv = argv;
+    loop {
+        let skip = match argv.first().map(|arg| basename(arg)) {
+            Some("sudo") | Some("doas") => skip_options(
+                argv,
+                &[
+                    "-u", "-g", "-C", "-D", "-h", "-p", "-r", "-t", "-T", "-U", "--user",
+                    "--group", "--host", "--prompt", "--chdir",
+                ],
+            ),
+            Some("env") => {
+                let index = skip_options(argv, &["-u", "-C", "-S", "--unset", "--chdir"]);
+                // NAME=VALUE
+                index
+                    + argv[index.min(argv.len())..]
+                        .iter()
+                        .take_while(|arg| arg.contains('='))
+                        .count()
+            }
+            Some("nice") => skip_options(argv, &["-n", "--adjustment"]),
+            Some("time") => skip_options(argv, &["-f", "-o", "--format", "--output"]),
+            // The host follows the options
+            Some("ssh") => {
+                skip_options(
+                    argv,
+                    &[
+                        "-b", "-c", "-D", "-E", "-e", "-F", "-I", "-i", "-J", "-L", "-l", "-m",
+                        "-O", "-o", "-p", "-Q", "-R", "-S", "-W", "-w",
+                    ],
+                ) + 1
+            }
+            _ => return argv,
+        };
+        argv = &argv[skip.min(argv.len())..];
+    }
+}
+
+// Number of leading args taken by a wrapper: its name, its options (and the values of
+// `value_options`, e.g. "-u root") and a "--" ending them
+fn skip_options(argv: &[String], value_options: &[&str]) -> usize {
+    let mut index = 1;
+    while let Some(arg) = argv.get(index) {
+        if arg == "--" {
+            return index + 1;
+        }
+        if !arg.starts_with('-') || arg == "-" {
+            break;
+        }
+        if value_options.contains(&arg.as_str()) {
+            index += 1;
+        }
+        index += 1;
+    }
+    index
+}
+
+// "/usr/sbin/fdisk" -> "fdisk", also "C:\Windows\ping.exe" -> "ping"
+fn basename(arg: &str) -> &str {
+    let name = arg.rsplit(|c| c == '/' || c == '\\').next().unwrap_or(arg);
+    name.strip_suffix(".exe").unwrap_or(name)
+}
+
+// Captured outputs of a profile, `<dir>/<profile>/*.txt` with "docker ps" stored as "docker-ps",
+// sorted so the order doesn't depend on the file system
+pub fn samples(dir: &Path, name: &str) -> Vec<PathBuf> {
+    let mut paths = match fs::read_dir(dir.join(name.replace(' ', "-"))) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().map_or(false, |ext| ext == "txt"))
+            .collect::<Vec<_>>(),
+        Err(_) => vec![],
+    };
+    paths.sort();
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Clap;
+
+    use super::*;
+    use crate::cli::cli::Opts;
+
+    fn detect_name(cmdline: &str) -> Option<&'static str> {
+        let argv: Vec<String> = cmdline.split(' ').map(String::from).collect();
+        let mappings = [
+            Mapping {
+                pattern: Pattern::Name(String::from("podman")),
+                target: String::from("docker"),
+            },
+            Mapping {
+                pattern: Pattern::Name(String::from("lsblk")),
+                target: String::from("findmnt"),
+            },
+            Mapping {
+                pattern: Pattern::Regex(LazyRegex::new("^ip -s link")),
+                target: String::from("ifconfig"),
+            },
+        ];
+        detect(&argv, &mappings).map(|profile| profile.name)
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(detect_name("/usr/sbin/fdisk -l"), Some("fdisk"));
+        assert_eq!(detect_name("./ping 1.1.1.1"), Some("ping"));
+        assert_eq!(detect_name("/usr/bin/ping6 ::1"), Some("ping"));
+        assert_eq!(detect_name("/usr/sbin/fping -C 3 1.1.1.1"), Some("fping"));
+        assert_eq!(
+            detect_name("C:\\Windows\\System32\\ping.exe -n 3"),
+            Some("ping")
+        );
+    }
+
+    #[test]
+    fn wrappers() {
+        assert_eq!(detect_name("sudo df -h"), Some("df"));
+        assert_eq!(
+            detect_name("sudo -E -u root /usr/sbin/fdisk -l"),
+            Some("fdisk")
+        );
+        assert_eq!(
+            detect_name("env -i LANG=C TZ=UTC journalctl -f"),
+            Some("journalctl")
+        );
+        assert_eq!(detect_name("nice -n 10 du -sh"), Some("du"));
+        assert_eq!(detect_name("time -f %e df"), Some("df"));
+        assert_eq!(
+            detect_name("sudo -- nice -5 time env A=1 free -h"),
+            Some("free")
+        );
+    }
+
+    #[test]
+    fn ssh() {
+        assert_eq!(detect_name("ssh host df -h"), Some("df"));
+        assert_eq!(
+            detect_name("ssh -p 2222 -i key user@host docker ps -a"),
+            Some("docker ps")
+        );
+        assert_eq!(detect_name("ssh host"), None);
+    }
+
+    #[test]
+    fn mappings() {
+        assert_eq!(detect_name("podman ps -a"), Some("docker ps"));
+        assert_eq!(
+            detect_name("sudo /usr/bin/podman images"),
+            Some("docker images")
+        );
+        assert_eq!(detect_name("lsblk -f"), Some("findmnt"));
+        assert_eq!(detect_name("sudo nerdctl ps"), Some("docker ps"));
+        assert_eq!(detect_name("ip -s link show"), Some("ifconfig"));
+        assert_eq!(detect_name("ip addr"), None);
+    }
+
+    // The built-in command which runs a command line for a profile
+    fn parse(profile: &str, cmdline: &str) -> Option<(&'static str, ArgMatches)> {
+        let argv: Vec<String> = cmdline.split(' ').map(String::from).collect();
+        find(profile)
+            .unwrap()
+            .parse(effective_argv(&argv))
+            .map(|(command, matches)| (command.name, matches))
+    }
+
+    #[test]
+    fn commands_of_profiles() {
+        let (name, matches) = parse("df", "sudo df -i").unwrap();
+        assert_eq!(name, "df");
+        assert!(matches.is_present("inodes"));
+        let (name, matches) = parse("journalctl", "sudo journalctl -o json").unwrap();
+        assert_eq!(name, "journalctl");
+        assert_eq!(matches.value_of("output"), Some("json"));
+        assert_eq!(parse("ping", "ssh host ping 1.1.1.1").unwrap().0, "ping");
+        assert_eq!(parse("ping", "ping6 ::1").unwrap().0, "ping6");
+        assert_eq!(parse("docker ps", "sudo podman ps -a").unwrap().0, "podman");
+
+        // Only the palettes
+        assert!(parse("findmnt", "lsblk -f").is_none());
+        assert!(parse("df", "ls -l").is_none());
+        assert!(parse("df inodes", "ping 1.1.1.1").is_none());
+        assert!(parse("ping", "ping --no-such-option").is_none());
+    }
+
+    // `ufc <options> <argv>`
+    fn context(options: &[&str], argv: &[&str]) -> Context {
+        let args: Vec<&str> = std::iter::once("ufc")
+            .chain(options.iter().copied())
+            .chain(argv.iter().copied())
+            .collect();
+        Context::new(
+            Opts::parse_from(args),
+            argv.iter().map(|arg| arg.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn forced_profile() {
+        let profile = find("df inodes").unwrap();
+        let detected = profile.command(&context(&[], &["sudo", "df", "-i"]));
+        assert_eq!(detected.unwrap().0.name, "df");
+
+        // The palettes of the profile, not the ones df picks by its args
+        let forced = ["--profile", "df inodes"];
+        assert!(profile.command(&context(&forced, &["df"])).is_none());
+        assert!(profile.command(&context(&forced, &["df", "-h"])).is_none());
+        let forced = ["--profile", "docker ps"];
+        let profile = find("docker ps").unwrap();
+        assert!(profile
+            .command(&context(&forced, &["docker", "images"]))
+            .is_none());
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(detect_name("sudo"), None);
+        assert_eq!(detect_name("sudo ls -l"), None);
+        assert_eq!(detect_name("docker run -it alpine"), None);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
+use clap::{App, AppSettings, Arg, ArgMatches};
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn new() -> App<'static> {
+        App::new("tcping")
+            .args(&[
+                Arg::new("host").about("Host to connect to").required(true),
+                Arg::new("port").about("TCP port, 80 by default"),
+                Arg::new("t")
+                    .short('t')
+                    .about("Ping continuously until stopped via control-c."),
+                Arg::new("count")
+                    .short('n')
+                    .takes_value(true)
+                    .about("Send count pings, 4 by default."),
+                Arg::new("interval")
+                    .short('i')
+                    .takes_value(true)
+                    .about("Interval in seconds between pings."),
+                Arg::new("timeout")
+                    .short('w')
+                    .takes_value(true)
+                    .about("Timeout in seconds to wait for a response."),
+                Arg::new("d")
+                    .short('d')
+                    .about("Include date and time on each line."),
+                Arg::new("s").short('s').about("Exit on a successful ping."),
+                Arg::new("4").short('4').about("Prefer IPv4."),
+                Arg::new("6").short('6').about("Prefer IPv6."),
+            ])
+            .setting(AppSettings::ArgRequiredElseHelp)
+            .about("tcping")
+    }
+
+    pub fn parse(ctx: Context, _app: &ArgMatches) -> i32 {
+        pre_exec(ctx, registry::palettes("tcping"))
+    }
+}
@@ -1,6 +1,8 @@
-use crate::cli::cli::{pre_exec, Colors, Palette};
+use crate::cli::{
+    cli::{pre_exec, Context},
+    registry,
+};
 use clap::{App, ArgMatches};
-use fancy_regex::Regex;
 
 pub struct Cmd {}
 
@@ -9,53 +11,8 @@ impl Cmd {
         App::new("top").about("top")
     }
 
-    pub fn parse(_app: &ArgMatches) {
+    pub fn parse(ctx: Context, _app: &ArgMatches) -> i32 {
         // print!("{:?}", app);
-        pre_exec(Cmd::palette());
-    }
-
-    fn palette() -> Vec<Palette<'static>> {
-        vec![
-            // Header
-            Palette {
-                regexp: Regex::new(r#"\s+PID.+COMMAND.+$"#).unwrap(),
-                colors: vec![&Colors::BlackOnGreen],
-            },
-            // Swap
-            Palette {
-                regexp: Regex::new(r#"Swap"#).unwrap(),
-                colors: vec![&Colors::BMagenta],
-            },
-            // Mem
-            Palette {
-                regexp: Regex::new(r#"Mem"#).unwrap(),
-                colors: vec![&Colors::BCyan],
-            },
-            // Time
-            Palette {
-                regexp: Regex::new(r#"\d+:\d+[:\.]\d+"#).unwrap(),
-                colors: vec![&Colors::BBlue],
-            },
-            // Size 'T'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\dt|\b\d{10,12}\b"#).unwrap(),
-                colors: vec![&Colors::BRed],
-            },
-            // Size 'G'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\dg|\b\d{7,9}\b"#).unwrap(),
-                colors: vec![&Colors::Red],
-            },
-            // Size 'M'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\dm|\b\d{4,6}\b"#).unwrap(),
-                colors: vec![&Colors::Yellow],
-            },
-            // Size 'K'
-            Palette {
-                regexp: Regex::new(r#"\s\d*[.,]?\dk?"#).unwrap(),
-                colors: vec![&Colors::Green],
-            },
-        ]
+        pre_exec(ctx, registry::palettes("top"))
     }
 }
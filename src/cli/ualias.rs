@@ -8,7 +8,7 @@ impl Cmd {
             .about("ualias gen (like alias but with a 'u' prefixed for each subcommand)")
     }
 
-    pub fn gen(subcommands: &Vec<&str>) {
+    pub fn gen(subcommands: &[&str]) {
         // let subcommands = vec!["df", "docker", "dig", "du", "env", "fdisk", "free", "ping", "top"];
         let mut alias = String::new();
         for cmd_str in subcommands.iter() {
@@ -0,0 +1,416 @@
+use std::{
+    io::{self, Write},
+    mem,
+    str::FromStr,
+};
+
+use termcolor::WriteColor;
+
+use crate::{
+    colors::{get_color, Colors},
+    gauge::{self, Thresholds},
+    palette::Palette,
+};
+
+// The colorizer without any global state, so other tools can embed the palettes of ufc:
+//
+//     let colorizer = Colorizer::new(ufc::palettes("df").unwrap().iter().collect());
+//     for span in colorizer.colorize_line("/dev/sda1  20G  15G  4.1G  79% /") {
+//         println!("{}..{} {:?}", span.start, span.end, span.style);
+//     }
+//
+// Palettes are applied in order and a later one never recolors what an earlier one colored.
+#[derive(Debug)]
+pub struct Colorizer<'a> {
+    palettes: Vec<&'a Palette<'a>>,
+    // Levels of the gauge rules, see `gauge`
+    thresholds: Thresholds,
+}
+
+#[derive(Debug)]
+struct ColorString<'a> {
+    pub text: String,
+    pub color: &'a Colors,
+    // Index of the palette which colored this string
+    pub rule: Option<usize>,
+}
+
+// A colored part of a line, `start` and `end` are byte offsets and `rule` the index of the palette
+#[derive(Debug, PartialEq)]
+pub struct Span<'a> {
+    pub start: usize,
+    pub end: usize,
+    pub style: &'a Colors,
+    pub rule: usize,
+}
+
+impl<'a> Colorizer<'a> {
+    pub fn new(palettes: Vec<&'a Palette<'a>>) -> Self {
+        Self {
+            palettes,
+            thresholds: Thresholds::default(),
+        }
+    }
+
+    // Levels of the gauges instead of the defaults, e.g. from --warn and --crit
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    pub fn palettes(&self) -> &[&'a Palette<'a>] {
+        &self.palettes
+    }
+
+    pub fn colorize_line(&self, line: &str) -> Vec<Span<'a>> {
+        spans(self.colorize(line))
+    }
+
+    // Same as `colorize_line`, plus one trace line per rule tried on the line, see `--explain`
+    pub fn explain_line(&self, line: &str) -> (Vec<Span<'a>>, Vec<String>) {
+        let (main_string, trace) = self.explain(line);
+        (spans(main_string), trace)
+    }
+
+    // Split a line into colored strings, the uncolored parts are `Colors::Default` without a rule
+    fn colorize(&self, line: &str) -> Vec<ColorString<'a>> {
+        self.segments(line, None)
+    }
+
+    fn explain(&self, line: &str) -> (Vec<ColorString<'a>>, Vec<String>) {
+        let mut trace = vec![format!("line {:?}", line)];
+        let main_string = self.segments(line, Some(&mut trace));
+        (main_string, trace)
+    }
+
+    fn segments(&self, line: &str, mut trace: Option<&mut Vec<String>>) -> Vec<ColorString<'a>> {
+        let mut prev_color = &Colors::Default;
+        let mut main_string = vec![ColorString {
+            text: line.to_string(),
+            color: &Colors::Default,
+            rule: None,
+        }];
+        for (rule, palette) in self.palettes.iter().enumerate() {
+            let mut is_tried = false;
+            let mut is_matched = false;
+            let mut index = 0;
+            // Instead of using a for loop, the size of main_string will grow so we have to use while loop
+            // https://stackoverflow.com/questions/47338839
+            while index < main_string.len() {
+                // println!("i={} main_string.len()={}", index, main_string.len());
+
+                if !main_string[index].color.eq(&Colors::Default) {
+                    index += 1;
+                    continue; // Ignore those already been colored
+                }
+
+                is_tried = true;
+                match palette
+                    .regexp
+                    .captures(main_string[index].text.as_str())
+                    .unwrap_or_else(|e| {
+                        // e.g. backtrack limit exceeded, skip this palette for this string
+                        eprintln!("ufc: palette \"{}\": {}", palette.name, e);
+                        None
+                    }) {
+                    Some(captures) => {
+                        let str = main_string[index].text.as_str();
+                        let mut colored_strings: Vec<ColorString> = vec![];
+
+                        // Non-matched start
+                        let start = 0;
+                        let end = captures.get(0).unwrap().start();
+                        colored_strings.push(ColorString {
+                            text: String::from_str(&str[start..end]).unwrap(),
+                            color: &Colors::Default,
+                            rule: None,
+                        });
+
+                        // A gauge colors the full match by the level of its number
+                        let level_color = gauge::value_of(&captures).map(|(unit, value)| {
+                            let level = self.thresholds.level(unit, value);
+                            palette.colors[level.min(palette.colors.len() - 1)]
+                        });
+
+                        // captures[0] -> Full match
+                        // captures[1..] -> Group match
+                        let mut last_start = captures.get(0).unwrap().start();
+                        let full_match_end = captures.get(0).unwrap().end();
+                        let mut is_full_match = false;
+                        for (i, capture) in captures.iter().enumerate() {
+                            if i == 0 {
+                                let mut color = level_color.unwrap_or(palette.colors[0]);
+                                if color == &Colors::UnChanged {
+                                    color = prev_color;
+                                }
+                                colored_strings.push(ColorString {
+                                    text: String::from_str(&str[last_start..full_match_end])
+                                        .unwrap(),
+                                    color: color,
+                                    rule: Some(rule),
+                                });
+                                prev_color = color;
+                                if level_color.is_some() {
+                                    break;
+                                }
+                                continue;
+                            }
+
+                            match capture {
+                                // A group nested in a previous one is already colored by it
+                                Some(capture) if capture.start() < last_start => {
+                                    continue;
+                                }
+                                Some(_) => {
+                                    if !is_full_match {
+                                        colored_strings.pop();
+                                    }
+                                    is_full_match = true;
+                                }
+                                None => {
+                                    continue;
+                                }
+                            }
+
+                            let start = capture.unwrap().start();
+                            let end = capture.unwrap().end();
+
+                            let mut color = palette.colors[0];
+                            if color == &Colors::UnChanged {
+                                color = prev_color;
+                            }
+
+                            colored_strings.push(ColorString {
+                                text: String::from_str(&str[last_start..start]).unwrap(),
+                                color: &color,
+                                rule: Some(rule),
+                            });
+                            prev_color = color;
+
+                            if i < palette.colors.len() {
+                                color = palette.colors[i];
+                                if color == &Colors::UnChanged {
+                                    color = prev_color;
+                                }
+                            }
+                            colored_strings.push(ColorString {
+                                text: String::from_str(&str[start..end]).unwrap(),
+                                color: color,
+                                rule: Some(rule),
+                            });
+                            prev_color = color;
+
+                            last_start = end;
+                        }
+
+                        // Non-matched end
+                        if !is_full_match {
+                            last_start = captures.get(0).unwrap().end();
+                        }
+
+                        colored_strings.push(ColorString {
+                            text: String::from_str(&str[last_start..]).unwrap(),
+                            color: &Colors::Default,
+                            rule: None,
+                        });
+
+                        // println!("colored_strings={:?}", colored_strings);
+                        // println!("colored_strings={:?}", colored_strings);
+
+                        is_matched = true;
+                        if let Some(trace) = trace.as_mut() {
+                            // Offset of this string in the line
+                            let mut start: usize =
+                                main_string[..index].iter().map(|str| str.text.len()).sum();
+                            for str in colored_strings.iter() {
+                                let end = start + str.text.len();
+                                if str.rule.is_some() && start != end {
+                                    trace.push(format!(
+                                        "  {:>3} {:?} {}..{} {:?} -> {}",
+                                        rule,
+                                        palette.name,
+                                        start,
+                                        end,
+                                        str.text,
+                                        str.color.name()
+                                    ));
+                                }
+                                start = end;
+                            }
+                        }
+
+                        main_string[index].text = String::new();
+                        main_string.remove(index);
+                        main_string.splice((index)..(index), colored_strings);
+                        index += 1;
+                    }
+                    None => {}
+                };
+                index += 1;
+            }
+            if let (Some(trace), true) = (trace.as_mut(), is_tried && !is_matched) {
+                trace.push(format!(
+                    "  {:>3} {:?} no match {}",
+                    rule,
+                    palette.name,
+                    palette.regexp.as_str()
+                ));
+            }
+        }
+
+        // Remove empty strings
+        main_string.retain(|color_string| color_string.text != "");
+        main_string
+    }
+}
+
+// The colored strings as byte offsets, without the uncolored ones
+fn spans(main_string: Vec<ColorString>) -> Vec<Span> {
+    let mut spans = vec![];
+    let mut start = 0;
+    for str in main_string {
+        let end = start + str.text.len();
+        if let (Some(rule), false) = (str.rule, str.color == &Colors::Default) {
+            spans.push(Span {
+                start,
+                end,
+                style: str.color,
+                rule,
+            });
+        }
+        start = end;
+    }
+    spans
+}
+
+// Colorize everything written to it line by line, e.g.
+//
+//     let mut writer = ColorWriter::new(colorizer, StandardStream::stdout(ColorChoice::Auto));
+//     writeln!(writer, "PING 1.1.1.1 (1.1.1.1): 56 data bytes")?;
+//
+// A line is buffered until its newline, `flush` colorizes and writes the incomplete one
+pub struct ColorWriter<'a, W: WriteColor> {
+    colorizer: Colorizer<'a>,
+    inner: W,
+    line: Vec<u8>,
+}
+
+impl<'a, W: WriteColor> ColorWriter<'a, W> {
+    pub fn new(colorizer: Colorizer<'a>, inner: W) -> Self {
+        Self {
+            colorizer,
+            inner,
+            line: vec![],
+        }
+    }
+
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.flush()?;
+        Ok(self.inner)
+    }
+
+    fn write_line(&mut self, line: &[u8]) -> io::Result<()> {
+        for str in self.colorizer.colorize(&String::from_utf8_lossy(line)) {
+            self.inner.set_color(&get_color(str.color))?;
+            self.inner.write_all(str.text.as_bytes())?;
+        }
+        self.inner.reset()
+    }
+}
+
+impl<'a, W: WriteColor> Write for ColorWriter<'a, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.line.extend_from_slice(buf);
+        while let Some(end) = self.line.iter().position(|byte| *byte == b'\n') {
+            let rest = self.line.split_off(end + 1);
+            let line = mem::replace(&mut self.line, rest);
+            self.write_line(&line[..end])?;
+            self.inner.write_all(b"\n")?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.line.is_empty() {
+            let line = mem::take(&mut self.line);
+            self.write_line(&line)?;
+        }
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use termcolor::Ansi;
+
+    use super::*;
+    use crate::palette::LazyRegex;
+
+    fn palettes() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Error",
+                regexp: LazyRegex::new(r#"error"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Number",
+                regexp: LazyRegex::new(r#"\d+"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "Word",
+                regexp: LazyRegex::new(r#"\w+"#),
+                colors: vec![&Colors::Blue],
+            },
+        ]
+    }
+
+    #[test]
+    fn colorize_line() {
+        let palettes = palettes();
+        let colorizer = Colorizer::new(palettes.iter().collect());
+        // Byte offsets past the 2 bytes of "é", and a later rule never recolors "error" or "42"
+        assert_eq!(
+            colorizer.colorize_line("é error 42"),
+            vec![
+                Span {
+                    start: 0,
+                    end: 2,
+                    style: &Colors::Blue,
+                    rule: 2
+                },
+                Span {
+                    start: 3,
+                    end: 8,
+                    style: &Colors::Red,
+                    rule: 0
+                },
+                Span {
+                    start: 9,
+                    end: 11,
+                    style: &Colors::Yellow,
+                    rule: 1
+                },
+            ]
+        );
+        assert_eq!(colorizer.colorize_line(" - "), vec![]);
+        assert_eq!(colorizer.colorize_line(""), vec![]);
+    }
+
+    #[test]
+    fn color_writer() {
+        let palettes = palettes();
+        let mut writer =
+            ColorWriter::new(Colorizer::new(palettes.iter().collect()), Ansi::new(vec![]));
+        // A line split across writes is colored as a whole, the last one without "\n" on flush
+        writer.write_all(b"error 4").unwrap();
+        writer.write_all(b"2\n- ").unwrap();
+        writer.write_all(b"7").unwrap();
+        let output = String::from_utf8(writer.into_inner().unwrap().into_inner()).unwrap();
+        assert_eq!(
+            output,
+            "\x1b[0m\x1b[31merror\x1b[0m \x1b[0m\x1b[33m42\x1b[0m\n\x1b[0m- \x1b[0m\x1b[33m7\x1b[0m"
+        );
+    }
+}
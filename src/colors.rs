@@ -0,0 +1,277 @@
+use termcolor::{Color, ColorSpec};
+
+#[allow(dead_code)]
+#[derive(Debug, PartialEq)]
+pub enum Colors {
+    UnChanged,
+    Default,
+    Black,
+    Blue,
+    Green,
+    Red,
+    Cyan,
+    Magenta,
+    Yellow,
+    White,
+    // BOld
+    BDefault,
+    BBlack,
+    BBlue,
+    BGreen,
+    BRed,
+    BCyan,
+    BMagenta,
+    BYellow,
+    BWhite,
+    // Dark
+    DDefault,
+    DBlack,
+    DBlue,
+    DGreen,
+    DRed,
+    DCyan,
+    DMagenta,
+    DYellow,
+    DWhite,
+    // Backgroud Color
+    OnBlack,
+    OnBlue,
+    OnGreen,
+    OnRed,
+    OnCyan,
+    OnMagenta,
+    OnYellow,
+    OnWhite,
+    BlackOnBlack,
+    BlackOnBlue,
+    BlackOnGreen,
+    BlackOnRed,
+    BlackOnCyan,
+    BlackOnMagenta,
+    BlackOnYellow,
+    BlackOnWhite,
+    // Undoerline
+    UDefault,
+    UBlack,
+    UBlue,
+    UGreen,
+    URed,
+    UCyan,
+    UMagenta,
+    UYellow,
+    UWhite,
+    // Underline Bold
+    UBDefault,
+    UBBlack,
+    UBBlue,
+    UBGreen,
+    UBRed,
+    UBCyan,
+    UBMagenta,
+    UBYellow,
+    UBWhite,
+}
+
+impl Colors {
+    pub const ALL: [Self; 62] = [
+        Self::UnChanged,
+        Self::Default,
+        Self::Black,
+        Self::Blue,
+        Self::Green,
+        Self::Red,
+        Self::Cyan,
+        Self::Magenta,
+        Self::Yellow,
+        Self::White,
+        Self::BDefault,
+        Self::BBlack,
+        Self::BBlue,
+        Self::BGreen,
+        Self::BRed,
+        Self::BCyan,
+        Self::BMagenta,
+        Self::BYellow,
+        Self::BWhite,
+        Self::DDefault,
+        Self::DBlack,
+        Self::DBlue,
+        Self::DGreen,
+        Self::DRed,
+        Self::DCyan,
+        Self::DMagenta,
+        Self::DYellow,
+        Self::DWhite,
+        Self::OnBlack,
+        Self::OnBlue,
+        Self::OnGreen,
+        Self::OnRed,
+        Self::OnCyan,
+        Self::OnMagenta,
+        Self::OnYellow,
+        Self::OnWhite,
+        Self::BlackOnBlack,
+        Self::BlackOnBlue,
+        Self::BlackOnGreen,
+        Self::BlackOnRed,
+        Self::BlackOnCyan,
+        Self::BlackOnMagenta,
+        Self::BlackOnYellow,
+        Self::BlackOnWhite,
+        Self::UDefault,
+        Self::UBlack,
+        Self::UBlue,
+        Self::UGreen,
+        Self::URed,
+        Self::UCyan,
+        Self::UMagenta,
+        Self::UYellow,
+        Self::UWhite,
+        Self::UBDefault,
+        Self::UBBlack,
+        Self::UBBlue,
+        Self::UBGreen,
+        Self::UBRed,
+        Self::UBCyan,
+        Self::UBMagenta,
+        Self::UBYellow,
+        Self::UBWhite,
+    ];
+
+    // Look up a color by its variant name, used by user-defined palettes
+    pub fn from_name(name: &str) -> Option<&'static Self> {
+        Self::ALL.iter().find(|color| color.name() == name)
+    }
+
+    pub fn name(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    // The same color underlined in bold, e.g. a match of --grep, the background colors which
+    // can't be underlined turn black on yellow
+    pub fn highlighted(&self) -> &'static Self {
+        match self {
+            Self::Black | Self::BBlack | Self::DBlack | Self::UBlack | Self::UBBlack => {
+                &Self::UBBlack
+            }
+            Self::Blue | Self::BBlue | Self::DBlue | Self::UBlue | Self::UBBlue => &Self::UBBlue,
+            Self::Green | Self::BGreen | Self::DGreen | Self::UGreen | Self::UBGreen => {
+                &Self::UBGreen
+            }
+            Self::Red | Self::BRed | Self::DRed | Self::URed | Self::UBRed => &Self::UBRed,
+            Self::Cyan | Self::BCyan | Self::DCyan | Self::UCyan | Self::UBCyan => &Self::UBCyan,
+            Self::Magenta | Self::BMagenta | Self::DMagenta | Self::UMagenta | Self::UBMagenta => {
+                &Self::UBMagenta
+            }
+            Self::Yellow | Self::BYellow | Self::DYellow | Self::UYellow | Self::UBYellow => {
+                &Self::UBYellow
+            }
+            Self::White | Self::BWhite | Self::DWhite | Self::UWhite | Self::UBWhite => {
+                &Self::UBWhite
+            }
+            Self::UnChanged
+            | Self::Default
+            | Self::BDefault
+            | Self::DDefault
+            | Self::UDefault
+            | Self::UBDefault => &Self::UBDefault,
+            _ => &Self::BlackOnYellow,
+        }
+    }
+}
+
+pub fn get_color(color: &Colors) -> ColorSpec {
+    let mut col = ColorSpec::new();
+    match color {
+        Colors::UnChanged => col // This one should be unreable
+            .set_fg(Some(Color::Magenta))
+            .set_intense(true)
+            .set_underline(true),
+        Colors::Default => col.set_fg(None),
+        Colors::BDefault => col.set_bold(true).set_fg(None),
+        Colors::Black => col.set_fg(Some(Color::Black)),
+        Colors::Blue => col.set_fg(Some(Color::Blue)),
+        Colors::Green => col.set_fg(Some(Color::Green)),
+        Colors::Red => col.set_fg(Some(Color::Red)),
+        Colors::Cyan => col.set_fg(Some(Color::Cyan)),
+        Colors::Magenta => col.set_fg(Some(Color::Magenta)),
+        Colors::Yellow => col.set_fg(Some(Color::Yellow)),
+        Colors::White => col.set_fg(Some(Color::White)),
+        Colors::BBlack => col.set_bold(true).set_fg(Some(Color::Ansi256(8))),
+        Colors::BBlue => col.set_bold(true).set_fg(Some(Color::Blue)),
+        Colors::BGreen => col.set_bold(true).set_fg(Some(Color::Green)),
+        Colors::BRed => col.set_bold(true).set_fg(Some(Color::Red)),
+        Colors::BCyan => col.set_bold(true).set_fg(Some(Color::Cyan)),
+        Colors::BMagenta => col.set_bold(true).set_fg(Some(Color::Magenta)),
+        Colors::BYellow => col.set_bold(true).set_fg(Some(Color::Yellow)),
+        Colors::BWhite => col.set_bold(true).set_fg(Some(Color::White)),
+        Colors::UDefault => col.set_underline(true).set_fg(None),
+        Colors::UBlack => col.set_underline(true).set_fg(Some(Color::Black)),
+        Colors::UBlue => col.set_underline(true).set_fg(Some(Color::Blue)),
+        Colors::UGreen => col.set_underline(true).set_fg(Some(Color::Green)),
+        Colors::URed => col.set_underline(true).set_fg(Some(Color::Red)),
+        Colors::UCyan => col.set_underline(true).set_fg(Some(Color::Cyan)),
+        Colors::UMagenta => col.set_underline(true).set_fg(Some(Color::Magenta)),
+        Colors::UYellow => col.set_underline(true).set_fg(Some(Color::Yellow)),
+        Colors::UWhite => col.set_underline(true).set_fg(Some(Color::White)),
+        Colors::UBBlack => col
+            .set_underline(true)
+            .set_bold(true)
+            .set_fg(Some(Color::Black)),
+        Colors::UBBlue => col
+            .set_underline(true)
+            .set_bold(true)
+            .set_fg(Some(Color::Blue)),
+        Colors::UBGreen => col
+            .set_underline(true)
+            .set_bold(true)
+            .set_fg(Some(Color::Green)),
+        Colors::UBRed => col
+            .set_underline(true)
+            .set_bold(true)
+            .set_fg(Some(Color::Red)),
+        Colors::UBCyan => col
+            .set_underline(true)
+            .set_bold(true)
+            .set_fg(Some(Color::Cyan)),
+        Colors::UBMagenta => col
+            .set_underline(true)
+            .set_bold(true)
+            .set_fg(Some(Color::Magenta)),
+        Colors::UBYellow => col
+            .set_underline(true)
+            .set_bold(true)
+            .set_fg(Some(Color::Yellow)),
+        Colors::UBWhite => col
+            .set_underline(true)
+            .set_bold(true)
+            .set_fg(Some(Color::White)),
+        Colors::UBDefault => col.set_underline(true).set_bold(true).set_fg(None),
+        Colors::OnBlack => col.set_bg(Some(Color::Black)),
+        Colors::OnBlue => col.set_bg(Some(Color::Blue)),
+        Colors::OnGreen => col.set_bg(Some(Color::Green)),
+        Colors::OnRed => col.set_bg(Some(Color::Red)),
+        Colors::OnCyan => col.set_bg(Some(Color::Cyan)),
+        Colors::OnMagenta => col.set_bg(Some(Color::Magenta)),
+        Colors::OnYellow => col.set_bg(Some(Color::Yellow)),
+        Colors::OnWhite => col.set_bg(Some(Color::White)),
+        Colors::BlackOnBlack => col.set_bg(Some(Color::Black)).set_fg(Some(Color::Black)),
+        Colors::BlackOnBlue => col.set_bg(Some(Color::Blue)).set_fg(Some(Color::Black)),
+        Colors::BlackOnGreen => col.set_bg(Some(Color::Green)).set_fg(Some(Color::Black)),
+        Colors::BlackOnRed => col.set_bg(Some(Color::Red)).set_fg(Some(Color::Black)),
+        Colors::BlackOnCyan => col.set_bg(Some(Color::Cyan)).set_fg(Some(Color::Black)),
+        Colors::BlackOnMagenta => col.set_bg(Some(Color::Magenta)).set_fg(Some(Color::Black)),
+        Colors::BlackOnYellow => col.set_bg(Some(Color::Yellow)).set_fg(Some(Color::Black)),
+        Colors::BlackOnWhite => col.set_bg(Some(Color::White)).set_fg(Some(Color::Black)),
+        Colors::DDefault => col.set_dimmed(true).set_fg(None),
+        Colors::DBlack => col.set_dimmed(true).set_fg(Some(Color::Black)),
+        Colors::DBlue => col.set_dimmed(true).set_fg(Some(Color::Blue)),
+        Colors::DGreen => col.set_dimmed(true).set_fg(Some(Color::Green)),
+        Colors::DRed => col.set_dimmed(true).set_fg(Some(Color::Red)),
+        Colors::DCyan => col.set_dimmed(true).set_fg(Some(Color::Cyan)),
+        Colors::DMagenta => col.set_dimmed(true).set_fg(Some(Color::Magenta)),
+        Colors::DYellow => col.set_dimmed(true).set_fg(Some(Color::Yellow)),
+        Colors::DWhite => col.set_dimmed(true).set_fg(Some(Color::White)),
+    };
+    col
+}
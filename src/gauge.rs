@@ -0,0 +1,310 @@
+use std::str::FromStr;
+
+use fancy_regex::{Captures, Regex};
+
+// A gauge is a rule which colors its match by the number it reads instead of by its groups.
+// The number is captured by a group named after the unit of a bare number:
+//
+//     (?P<percent>\d+)%      "92%"
+//     (?P<kib>\d+[KMGT]?)    "4.1G" or "8066460", 1K-blocks when there's no suffix
+//     (?P<bytes>\d+[KMGT]?)  "4.1G" or "4402341888"
+//     (?P<ms>[\d.]+) ?ms     "12.3 ms", or "1.2s" with its suffix
+//
+// and picks colors[0] below the warning threshold, colors[1] below the critical one, colors[2]
+// below the last one and colors[3] above it, e.g. Green, Yellow, Red and BRed for 70%, 90% and 98%
+const GROUPS: [(&str, Unit, f64); 4] = [
+    ("percent", Unit::Percent, 1.0),
+    ("kib", Unit::Size, 1024.0),
+    ("bytes", Unit::Size, 1.0),
+    ("ms", Unit::Time, 1.0),
+];
+
+const KIB: f64 = 1024.0;
+const MIB: f64 = KIB * 1024.0;
+const GIB: f64 = MIB * 1024.0;
+const TIB: f64 = GIB * 1024.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Unit {
+    Percent,
+    // Bytes
+    Size,
+    // Milliseconds
+    Time,
+}
+
+// The number and unit of the gauge group of a match, e.g. (Size, 4402341888.0) for "4.1G"
+pub fn value_of(captures: &Captures) -> Option<(Unit, f64)> {
+    GROUPS.iter().find_map(|(name, unit, scale)| {
+        let text = captures.name(name)?.as_str();
+        let (value, suffix) = split_number(text)?;
+        match unit {
+            Unit::Percent => Some((*unit, value)),
+            _ => Some((*unit, value * scale_of(*unit, suffix).unwrap_or(*scale))),
+        }
+    })
+}
+
+// Whether a rule is a gauge, its colors are levels and not the colors of its groups
+pub fn is_gauge(regex: &Regex) -> bool {
+    regex
+        .capture_names()
+        .flatten()
+        .any(|name| GROUPS.iter().any(|(group, _, _)| *group == name))
+}
+
+// "4,1 G" to (4.1, "G")
+fn split_number(text: &str) -> Option<(f64, &str)> {
+    let text = text.trim();
+    let end = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+        .unwrap_or_else(|| text.len());
+    let value = text[..end].replace(',', ".").parse().ok()?;
+    Some((value, text[end..].trim()))
+}
+
+// Bytes or milliseconds in one of a suffix, `None` without a suffix
+fn scale_of(unit: Unit, suffix: &str) -> Option<f64> {
+    if suffix.is_empty() {
+        return None;
+    }
+    match unit {
+        Unit::Percent => None,
+        Unit::Size => {
+            // "G", "Gi", "GB" or "GiB", powers of 1024 like df -h and free -h
+            let suffix = suffix.trim_end_matches(|c| c == 'B' || c == 'i');
+            match suffix {
+                "" => Some(1.0),
+                "K" | "k" => Some(KIB),
+                "M" => Some(MIB),
+                "G" => Some(GIB),
+                "T" => Some(TIB),
+                "P" => Some(TIB * 1024.0),
+                _ => None,
+            }
+        }
+        Unit::Time => match suffix {
+            "ns" => Some(0.000_001),
+            "us" | "µs" | "usec" => Some(0.001),
+            "ms" | "msec" => Some(1.0),
+            "s" | "sec" => Some(1000.0),
+            _ => None,
+        },
+    }
+}
+
+// A value of --warn or --crit, e.g. "80", "80%", "2G" or "150ms". A bare number is a
+// percentage or milliseconds, whichever the gauges of the command read
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Threshold {
+    pub value: f64,
+    pub unit: Option<Unit>,
+}
+
+impl FromStr for Threshold {
+    type Err = String;
+
+    fn from_str(src: &str) -> Result<Self, String> {
+        let invalid = || format!("invalid threshold \"{}\", e.g. 80, 80%, 2G or 150ms", src);
+        let (value, suffix) = split_number(src).ok_or_else(invalid)?;
+        if suffix.is_empty() {
+            return Ok(Self { value, unit: None });
+        }
+        if suffix == "%" {
+            return Ok(Self {
+                value,
+                unit: Some(Unit::Percent),
+            });
+        }
+        [Unit::Size, Unit::Time]
+            .iter()
+            .find_map(|unit| {
+                scale_of(*unit, suffix).map(|scale| Self {
+                    value: value * scale,
+                    unit: Some(*unit),
+                })
+            })
+            .ok_or_else(invalid)
+    }
+}
+
+// The levels of the gauges by unit: warning, critical and the last one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Thresholds {
+    pub percent: [f64; 3],
+    pub size: [f64; 3],
+    pub time: [f64; 3],
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            percent: [70.0, 90.0, 98.0],
+            size: [MIB, GIB, TIB],
+            time: [100.0, 500.0, 1000.0],
+        }
+    }
+}
+
+impl Thresholds {
+    // The defaults with --warn and --crit, which are kept as they are given
+    pub fn new(warn: Option<Threshold>, crit: Option<Threshold>) -> Self {
+        let mut thresholds = Self::default();
+        for unit in [Unit::Percent, Unit::Size, Unit::Time].iter() {
+            let value_of = |threshold: Option<Threshold>| {
+                threshold
+                    .filter(|threshold| match threshold.unit {
+                        Some(threshold_unit) => threshold_unit == *unit,
+                        None => *unit != Unit::Size,
+                    })
+                    .map(|threshold| threshold.value)
+            };
+            fit(thresholds.levels_mut(*unit), value_of(warn), value_of(crit));
+        }
+        thresholds
+    }
+
+    fn levels_mut(&mut self, unit: Unit) -> &mut [f64; 3] {
+        match unit {
+            Unit::Percent => &mut self.percent,
+            Unit::Size => &mut self.size,
+            Unit::Time => &mut self.time,
+        }
+    }
+
+    // Index of the color of a value, the number of levels it reaches
+    pub fn level(&self, unit: Unit, value: f64) -> usize {
+        let levels = match unit {
+            Unit::Percent => &self.percent,
+            Unit::Size => &self.size,
+            Unit::Time => &self.time,
+        };
+        levels.iter().filter(|level| value >= **level).count()
+    }
+}
+
+// Set the warning and critical levels, a default one out of order with them moves to fit
+// around them: between the level below and the next one above, or by the ratio of the
+// defaults when none is left above, e.g. --warn 95 with the percentages 70/90/98 moves the
+// critical level to 96.5% and --crit 60% the warning level to 46.7%
+fn fit(levels: &mut [f64; 3], warn: Option<f64>, crit: Option<f64>) {
+    let defaults = *levels;
+    if let Some(warn) = warn {
+        levels[0] = warn;
+    }
+    if let Some(crit) = crit {
+        levels[1] = crit;
+    }
+    if warn.is_none() && levels[0] >= levels[1] {
+        levels[0] = levels[1] * defaults[0] / defaults[1];
+    }
+    if crit.is_none() && levels[1] <= levels[0] {
+        // The geometric mean, so sizes (1M/1G/1T) move by their scale
+        levels[1] = match levels[2] > levels[0] {
+            true => (levels[0] * levels[2]).sqrt(),
+            false => levels[0] * defaults[1] / defaults[0],
+        };
+    }
+    let top = levels[0].max(levels[1]);
+    if levels[2] <= top {
+        levels[2] = top * defaults[2] / defaults[1];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(pattern: &str, text: &str) -> Option<(Unit, f64)> {
+        let regex = Regex::new(pattern).unwrap();
+        value_of(&regex.captures(text).unwrap().unwrap())
+    }
+
+    #[test]
+    fn values() {
+        let size = r#"(?P<kib>\d+(?:[.,]\d+)?[KMGT]?i?)"#;
+        assert_eq!(value(size, "8066460"), Some((Unit::Size, 8066460.0 * KIB)));
+        assert_eq!(value(size, "4,5G"), Some((Unit::Size, 4.5 * GIB)));
+        assert_eq!(value(size, "1023Mi"), Some((Unit::Size, 1023.0 * MIB)));
+        assert_eq!(
+            value(r#"time=(?P<ms>[\d.]+ ?m?s)"#, "time=1.5 s"),
+            Some((Unit::Time, 1500.0))
+        );
+        assert_eq!(
+            value(r#"(?P<ms>[\d.]+ ?[mu]sec)"#, "312.5 usec"),
+            Some((Unit::Time, 0.3125))
+        );
+        assert_eq!(
+            value(r#"(?P<percent>\d+)%"#, "92%"),
+            Some((Unit::Percent, 92.0))
+        );
+        assert_eq!(value(r#"(\d+)%"#, "92%"), None);
+    }
+
+    #[test]
+    fn levels() {
+        let thresholds = Thresholds::default();
+        assert_eq!(thresholds.level(Unit::Percent, 69.0), 0);
+        assert_eq!(thresholds.level(Unit::Percent, 92.0), 2);
+        assert_eq!(thresholds.level(Unit::Percent, 100.0), 3);
+        assert_eq!(thresholds.level(Unit::Size, 1023.0 * KIB), 0);
+        assert_eq!(thresholds.level(Unit::Size, 1.0 * MIB), 1);
+
+        let thresholds = Thresholds::new("80".parse().ok(), "99".parse().ok());
+        assert_eq!(thresholds.percent[..2], [80.0, 99.0]);
+        assert_eq!(thresholds.level(Unit::Percent, 99.5), 2);
+        assert_eq!(thresholds.time, [80.0, 99.0, 1000.0]);
+        assert_eq!(thresholds.size, Thresholds::default().size);
+
+        let thresholds = Thresholds::new("2G".parse().ok(), None);
+        assert_eq!(thresholds.size[0], 2.0 * GIB);
+        assert_eq!(thresholds.percent, Thresholds::default().percent);
+        assert!("80x".parse::<Threshold>().is_err());
+    }
+
+    #[test]
+    fn warn_only() {
+        // Above the default critical level, which moves between it and the last one
+        let thresholds = Thresholds::new("95".parse().ok(), None);
+        assert_eq!(thresholds.percent[0], 95.0);
+        assert_eq!(thresholds.percent[2], 98.0);
+        assert_eq!(thresholds.level(Unit::Percent, 92.0), 0);
+        assert_eq!(thresholds.level(Unit::Percent, 96.0), 1);
+        assert_eq!(thresholds.level(Unit::Percent, 97.0), 2);
+        assert_eq!(thresholds.level(Unit::Percent, 98.0), 3);
+
+        let thresholds = Thresholds::new("2G".parse().ok(), None);
+        assert_eq!(thresholds.size[0], 2.0 * GIB);
+        assert_eq!(thresholds.size[2], TIB);
+        assert_eq!(thresholds.level(Unit::Size, 1.5 * GIB), 0);
+        assert_eq!(thresholds.level(Unit::Size, 10.0 * GIB), 1);
+        assert_eq!(thresholds.level(Unit::Size, 100.0 * GIB), 2);
+
+        // Above the last level too, both move by the ratio of the defaults
+        let thresholds = Thresholds::new("150ms".parse().ok(), None);
+        assert_eq!(thresholds.time, [150.0, 500.0, 1000.0]);
+        let thresholds = Thresholds::new("2s".parse().ok(), None);
+        assert_eq!(thresholds.time, [2000.0, 10000.0, 20000.0]);
+
+        let thresholds = Thresholds::new("50".parse().ok(), None);
+        assert_eq!(thresholds.percent, [50.0, 90.0, 98.0]);
+    }
+
+    #[test]
+    fn crit_only() {
+        let thresholds = Thresholds::new(None, "99.5".parse().ok());
+        assert_eq!(thresholds.percent[..2], [70.0, 99.5]);
+        assert_eq!(thresholds.level(Unit::Percent, 99.7), 2);
+
+        // Below the default warning level, which moves below it
+        let thresholds = Thresholds::new(None, "60%".parse().ok());
+        assert_eq!(thresholds.percent[1..], [60.0, 98.0]);
+        assert_eq!(thresholds.level(Unit::Percent, 40.0), 0);
+        assert_eq!(thresholds.level(Unit::Percent, 50.0), 1);
+        assert_eq!(thresholds.level(Unit::Percent, 65.0), 2);
+        assert_eq!(thresholds.time, Thresholds::default().time);
+
+        let thresholds = Thresholds::new(None, "2s".parse().ok());
+        assert_eq!(thresholds.time, [100.0, 2000.0, 4000.0]);
+    }
+}
@@ -0,0 +1,32 @@
+#![forbid(unsafe_code)]
+#![deny(unused_imports)]
+#![deny(unused_must_use)]
+#![deny(dead_code)]
+#![deny(clippy::all, clippy::perf, clippy::nursery, clippy::pedantic)]
+#![deny(clippy::filetype_is_file)]
+#![deny(clippy::cargo)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::panic)]
+#![deny(clippy::match_like_matches_macro)]
+#![deny(clippy::needless_update)]
+
+// The colorizer and the built-in palettes as a library, the `ufc` binary is one of its consumers:
+//
+//     use ufc::Colorizer;
+//
+//     let colorizer = Colorizer::new(ufc::palettes("df").unwrap().iter().collect());
+//     let spans = colorizer.colorize_line("/dev/sda1  20G  15G  4.1G  79% /");
+
+mod colorizer;
+mod colors;
+mod gauge;
+mod palette;
+mod profiles;
+
+pub use colorizer::{ColorWriter, Colorizer, Span};
+// `Colors` is the style of a palette, e.g. `Style::BRed` or `Style::OnBlue`
+pub use colors::{get_color, Colors as Style};
+// The levels of the gauges, see `Colorizer::with_thresholds`
+pub use gauge::{Threshold, Thresholds};
+pub use palette::{LazyRegex, Palette};
+pub use profiles::{palettes, profiles};
@@ -0,0 +1,102 @@
+use std::{borrow::Cow, ops::Deref, sync::OnceLock};
+
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+
+use crate::{colors::Colors, gauge};
+
+#[derive(Debug)]
+pub struct Palette<'a> {
+    // Short description of what the rule colors, e.g. "Size 'G'"
+    pub name: &'a str,
+    pub regexp: LazyRegex,
+    pub colors: Vec<&'a Colors>,
+}
+
+impl Palette<'_> {
+    // Whether the rule is a gauge, its colors are levels and not the colors of its groups,
+    // see `Thresholds`. An invalid regex is none
+    pub fn is_gauge(&self) -> bool {
+        self.regexp.compile().map_or(false, gauge::is_gauge)
+    }
+}
+
+// A regex which is compiled the first time it is used, so a short output (e.g. `id`)
+// doesn't pay for compiling every rule of the palette. The error of an invalid one is kept
+// too, it is compiled once either way
+#[derive(Debug)]
+pub struct LazyRegex {
+    pattern: Cow<'static, str>,
+    regex: OnceLock<Result<Regex, fancy_regex::Error>>,
+}
+
+lazy_static! {
+    // Stands for an invalid regex once its error is reported, "." after the end never matches
+    static ref NEVER: Regex = Regex::new(r#"$."#).expect("a valid regex");
+}
+
+impl LazyRegex {
+    pub const fn new(pattern: &'static str) -> Self {
+        Self {
+            pattern: Cow::Borrowed(pattern),
+            regex: OnceLock::new(),
+        }
+    }
+
+    pub const fn from_string(pattern: String) -> Self {
+        Self {
+            pattern: Cow::Owned(pattern),
+            regex: OnceLock::new(),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    // Compile now and report the error instead of finding out on first use
+    pub fn compile(&self) -> Result<&Regex, &fancy_regex::Error> {
+        self.regex
+            .get_or_init(|| Regex::new(&self.pattern))
+            .as_ref()
+    }
+}
+
+// An invalid regex which wasn't `compile`d beforehand is reported once on stderr and then
+// matches nothing, instead of panicking in the middle of the output
+impl Deref for LazyRegex {
+    type Target = Regex;
+
+    fn deref(&self) -> &Regex {
+        let mut is_first = false;
+        let regex = self.regex.get_or_init(|| {
+            is_first = true;
+            Regex::new(&self.pattern)
+        });
+        match regex {
+            Ok(regex) => regex,
+            Err(e) => {
+                if is_first {
+                    eprintln!("ufc: invalid regex \"{}\": {}", self.pattern, e);
+                }
+                &NEVER
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_regex_matches_nothing() {
+        let regexp = LazyRegex::new(r#"(?P<percent>\d+%"#);
+        assert!(!regexp.is_match("92%").unwrap_or(true));
+        assert!(regexp.compile().is_err());
+
+        let regexp = LazyRegex::new(r#"(\d+)%"#);
+        assert!(regexp.compile().is_ok());
+        assert!(regexp.is_match("92%").unwrap_or(false));
+    }
+}
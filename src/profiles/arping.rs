@@ -0,0 +1,78 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+    profiles::ping,
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    // iputils ("Unicast reply from 192.168.1.1 [AA:BB:CC:DD:EE:FF]  1.234ms") and
+    // Thomas Habets' arping ("60 bytes from aa:bb:cc:dd:ee:ff (192.168.1.1): index=0 time=1.2 msec")
+    fn palette() -> Vec<Palette<'static>> {
+        let mut palettes = ping::Cmd::statistics_palette();
+        palettes.extend(vec![
+            Palette {
+                name: "ARPING",
+                regexp: LazyRegex::new(r#"^ARPING (\S+)"#),
+                colors: vec![&Colors::Default, &Colors::Blue],
+            },
+            Palette {
+                name: "Timeout",
+                regexp: LazyRegex::new(r#"\bTimeout\b|\bno response\b"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "No reply",
+                regexp: LazyRegex::new(r#"\bReceived 0 response\(s\)|\b100(?:\.0)?% unanswered"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "All answered",
+                regexp: LazyRegex::new(r#"\b0(?:\.0)?% unanswered"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Unanswered",
+                regexp: LazyRegex::new(r#"\b[\d.]+% unanswered"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "Reply",
+                regexp: LazyRegex::new(r#"\b(?:Unicast|Broadcast) reply\b"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "MAC",
+                regexp: LazyRegex::new(r#"\b[0-9a-fA-F]{2}(?::[0-9a-fA-F]{2}){5}\b"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "index=",
+                regexp: LazyRegex::new(r#"index=(\d+)"#),
+                colors: vec![&Colors::Default, &Colors::Magenta],
+            },
+            // A gauge like the latency of ping, without its "time=" with iputils
+            Palette {
+                name: "Latency",
+                regexp: LazyRegex::new(r#"(?P<ms>[0-9.]+ ?(?:[mu]sec|ms))\b"#),
+                colors: vec![&Colors::Green, &Colors::Yellow, &Colors::Red, &Colors::BRed],
+            },
+            Palette {
+                name: "IP",
+                regexp: LazyRegex::new(r#"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}"#),
+                colors: vec![&Colors::BBlue],
+            },
+        ]);
+        palettes
+    }
+}
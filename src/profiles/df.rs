@@ -0,0 +1,167 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+    static ref TYPE_PALETTES: Vec<Palette<'static>> = Cmd::type_palette();
+    static ref INODES_PALETTES: Vec<Palette<'static>> = Cmd::inodes_palette();
+    static ref OUTPUT_PALETTES: Vec<Palette<'static>> = Cmd::output_palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    pub fn type_palettes() -> &'static [Palette<'static>] {
+        &TYPE_PALETTES
+    }
+
+    pub fn inodes_palettes() -> &'static [Palette<'static>] {
+        &INODES_PALETTES
+    }
+
+    pub fn output_palettes() -> &'static [Palette<'static>] {
+        &OUTPUT_PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        let mut palettes = Cmd::header_palette();
+        palettes.extend(Cmd::filesystem_palette());
+        palettes.extend(Cmd::use_palette());
+        palettes.extend(Cmd::size_palette());
+        palettes
+    }
+
+    // df -T, the type is the column after the filesystem
+    fn type_palette() -> Vec<Palette<'static>> {
+        let mut palettes = Cmd::header_palette();
+        palettes.extend(Cmd::filesystem_palette());
+        palettes.push(Cmd::type_column_palette());
+        palettes.extend(Cmd::use_palette());
+        palettes.extend(Cmd::size_palette());
+        palettes
+    }
+
+    // df -i, the counts are numbers of inodes and not sizes, "-" when the filesystem has none
+    fn inodes_palette() -> Vec<Palette<'static>> {
+        let mut palettes = Cmd::header_palette();
+        palettes.extend(Cmd::filesystem_palette());
+        // Also with -T, a count never starts like a type
+        palettes.push(Cmd::type_column_palette());
+        palettes.extend(Cmd::use_palette());
+        palettes.extend(vec![
+            Palette {
+                name: "No inodes",
+                regexp: LazyRegex::new(r#"(?<=\s)-(?=\s|$)"#),
+                colors: vec![&Colors::DDefault],
+            },
+            Palette {
+                name: "Inodes",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+(?:[.,]\d+)?[KMGT]?(?=\s|$)"#),
+                colors: vec![&Colors::Cyan],
+            },
+        ]);
+        palettes
+    }
+
+    // df --output=FIELD_LIST, the columns are in any order so every value is told apart by its
+    // shape: devices, mount points, known types, percentages and sizes
+    fn output_palette() -> Vec<Palette<'static>> {
+        let mut palettes = Cmd::header_palette();
+        palettes.extend(vec![
+            Palette {
+                name: "tmpfs lines",
+                regexp: LazyRegex::new(r#"^\s*tmpfs\s.*"#),
+                colors: vec![&Colors::BBlack],
+            },
+            Palette {
+                name: "Device",
+                regexp: LazyRegex::new(r#"(?:^|(?<=\s))\/dev\/(\S+)"#),
+                colors: vec![&Colors::Blue, &Colors::BBlue],
+            },
+            Palette {
+                name: "Mounted on",
+                regexp: LazyRegex::new(r#"(?:^|(?<=\s))(?:\/[^\/\s]+)*\/([^\/\s]*)(?=\s|$)"#),
+                colors: vec![&Colors::Green, &Colors::BGreen],
+            },
+            Palette {
+                name: "Type",
+                regexp: LazyRegex::new(
+                    r#"(?<=\s)(?:ext\d|xfs|btrfs|zfs|f2fs|vfat|exfat|ntfs|msdos|nfs\d?|cifs|smb3|overlay|squashfs|iso9660|ramfs|devtmpfs|tmpfs|fuse[\w.]*)(?=\s|$)"#,
+                ),
+                colors: vec![&Colors::Cyan],
+            },
+        ]);
+        palettes.extend(Cmd::use_palette());
+        palettes.extend(Cmd::size_palette());
+        palettes
+    }
+
+    // "Filesystem 1024-blocks Used Available Capacity Mounted on" of -P, or the one of --output
+    // which may start with any column, its numbers aren't sizes
+    fn header_palette() -> Vec<Palette<'static>> {
+        vec![Palette {
+            name: "Header",
+            regexp: LazyRegex::new(
+                r#"^\s*(?:Filesystem|Type|Inodes|IUsed|IFree|IUse%|\d+\w*-blocks|Size|Used|Avail|Use%|File|Mounted on)(?:\s.*)?$"#,
+            ),
+            colors: vec![&Colors::BDefault],
+        }]
+    }
+
+    fn filesystem_palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "FS",
+                regexp: LazyRegex::new(r#"^(?!Filesystem)(\/[-\w\d.]+)+\s"#),
+                colors: vec![&Colors::Blue, &Colors::BBlue],
+            },
+            Palette {
+                name: "tmpfs lines",
+                regexp: LazyRegex::new(r#"^tmpfs.*"#),
+                colors: vec![&Colors::BBlack],
+            },
+            Palette {
+                name: "Mounted on",
+                regexp: LazyRegex::new(r#"\/$|(\/[-\w\d. ]+)+$"#),
+                colors: vec![&Colors::Green, &Colors::BGreen],
+            },
+        ]
+    }
+
+    // The word after the filesystem ("/dev/sda1  xfs  50G"), sizes and counts start with a digit
+    fn type_column_palette() -> Palette<'static> {
+        Palette {
+            name: "Type",
+            regexp: LazyRegex::new(r#"^(?:\S+)?\s+([a-z][\w.]*)(?=\s)"#),
+            colors: vec![&Colors::Default, &Colors::Cyan],
+        }
+    }
+
+    // Use% of the blocks or IUse% of the inodes, the last column but one or anywhere with --output.
+    // A gauge, 70%, 90% and 98% unless --warn and --crit say otherwise
+    fn use_palette() -> Vec<Palette<'static>> {
+        vec![Palette {
+            name: "Use%",
+            regexp: LazyRegex::new(r#"(?<=\s)(?P<percent>\d{1,3})%(?=\s|$)"#),
+            colors: vec![&Colors::Green, &Colors::Yellow, &Colors::Red, &Colors::BRed],
+        }]
+    }
+
+    // The sizes of -h ("4.1G") or the 1K-blocks, a gauge by bytes: 1M, 1G and 1T
+    fn size_palette() -> Vec<Palette<'static>> {
+        vec![Palette {
+            name: "Size",
+            regexp: LazyRegex::new(
+                r#"(?:^|(?<=\s))(?P<kib>\d+(?:[.,]\d+)?(?:[KMGTPB]i?)?)(?=\s|$)"#,
+            ),
+            colors: vec![&Colors::Green, &Colors::Yellow, &Colors::Red, &Colors::BRed],
+        }]
+    }
+}
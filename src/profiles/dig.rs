@@ -0,0 +1,58 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Title",
+                regexp: LazyRegex::new(r#"; <<>> DiG.* <<>> (\S+)"#),
+                colors: vec![&Colors::Default, &Colors::BMagenta],
+            },
+            Palette {
+                name: "comments",
+                regexp: LazyRegex::new(r#"^;;[\s\w]+"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "ipv6",
+                regexp: LazyRegex::new(r#"\t(([0-9a-fA-F]{1,4})?\:\:?[0-9a-fA-F]{1,4})+"#),
+                colors: vec![&Colors::DGreen],
+            },
+            Palette {
+                name: "ip4 address",
+                regexp: LazyRegex::new(r#"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "line",
+                regexp: LazyRegex::new(r#"^(\S+).*?(\d+)\t(\w+)\t(\w+)\t"#),
+                colors: vec![
+                    &Colors::UnChanged,
+                    &Colors::Magenta,
+                    &Colors::Red,
+                    &Colors::Yellow,
+                    &Colors::Cyan,
+                ],
+            },
+            Palette {
+                name: "domain",
+                regexp: LazyRegex::new(r#"[\S]+\."#),
+                colors: vec![&Colors::BMagenta],
+            },
+        ]
+    }
+}
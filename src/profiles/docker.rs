@@ -0,0 +1,13 @@
+pub mod build;
+pub mod compose;
+pub mod events;
+pub mod images;
+pub mod info;
+pub mod inspect;
+pub mod logs;
+pub mod network;
+pub mod ps;
+pub mod stats;
+pub mod system;
+pub mod version;
+pub mod volume;
@@ -0,0 +1,125 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    // The legacy builder prints "Step 1/4 : FROM alpine" and " ---> 14119a10abf4" for each layer,
+    // BuildKit prints "#5 [2/4] RUN apk add curl" followed by the lines of the same "#5"
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Step",
+                regexp: LazyRegex::new(r#"^(Step\s\d+/\d+)(\s:\s)([A-Z]+)\s"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::BCyan,
+                    &Colors::Default,
+                    &Colors::BYellow,
+                ],
+            },
+            Palette {
+                name: "Layer - cache",
+                regexp: LazyRegex::new(r#"^\s---> Using cache$"#),
+                colors: vec![&Colors::DGreen],
+            },
+            Palette {
+                name: "Layer - running",
+                regexp: LazyRegex::new(r#"^\s(--->)\s(Running in)\s([\da-f]{12})$"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::BBlack,
+                    &Colors::BBlack,
+                    &Colors::White,
+                ],
+            },
+            Palette {
+                name: "Layer",
+                regexp: LazyRegex::new(r#"^\s(--->)\s([\da-f]{12})$"#),
+                colors: vec![&Colors::Default, &Colors::BBlack, &Colors::BWhite],
+            },
+            Palette {
+                name: "Intermediate container",
+                regexp: LazyRegex::new(r#"^Removing intermediate container [\da-f]{12}$"#),
+                colors: vec![&Colors::BBlack],
+            },
+            Palette {
+                name: "Successfully",
+                regexp: LazyRegex::new(r#"^(Successfully (?:built|tagged))\s(\S+)$"#),
+                colors: vec![&Colors::Default, &Colors::BGreen, &Colors::BWhite],
+            },
+            Palette {
+                name: "BuildKit step - error",
+                regexp: LazyRegex::new(r#"^#\d+\s(?:ERROR|\d+\.\d+\sERROR)\b.*$"#),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "BuildKit step",
+                regexp: LazyRegex::new(
+                    r#"^(#\d+)\s(?:(\[[^\]]+\])\s(?:(FROM|RUN|CMD|LABEL|EXPOSE|ENV|ADD|COPY|ENTRYPOINT|VOLUME|USER|WORKDIR|ARG|ONBUILD|STOPSIGNAL|HEALTHCHECK|SHELL)\s)?)?"#,
+                ),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Cyan,
+                    &Colors::BCyan,
+                    &Colors::BYellow,
+                ],
+            },
+            // e.g. "#5 0.532 fetch https://dl-cdn.alpinelinux.org/..."
+            Palette {
+                name: "BuildKit time",
+                regexp: LazyRegex::new(r#"^\s?\d+\.\d+\s"#),
+                colors: vec![&Colors::BBlack],
+            },
+            Palette {
+                name: "BuildKit - CACHED",
+                regexp: LazyRegex::new(r#"^\s?CACHED$"#),
+                colors: vec![&Colors::DGreen],
+            },
+            Palette {
+                name: "BuildKit - DONE",
+                regexp: LazyRegex::new(r#"(?:^\s?|\s)(?:DONE\s\d+\.\d+s|done)$"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "BuildKit - CANCELED",
+                regexp: LazyRegex::new(r#"^\s?CANCELED.*$"#),
+                colors: vec![&Colors::Yellow],
+            },
+            // e.g. ">>> RUN make" and "executor failed running [/bin/sh -c make]: exit code: 2"
+            Palette {
+                name: "Errors",
+                regexp: LazyRegex::new(
+                    r#"^(?:ERROR|error|failed to solve|executor failed|The command '.*' returned).*$|exit code: [1-9]\d*|returned a non-zero code: \d+"#,
+                ),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "Digest",
+                regexp: LazyRegex::new(r#"sha256:[\da-f]{12,64}"#),
+                colors: vec![&Colors::BBlack],
+            },
+            Palette {
+                name: "Image name",
+                regexp: LazyRegex::new(r#"(?<=naming to\s)\S+"#),
+                colors: vec![&Colors::BWhite],
+            },
+            Palette {
+                name: "Sizes",
+                regexp: LazyRegex::new(r#"\b\d+[.,]?\d*[kKMG]?B\b"#),
+                colors: vec![&Colors::Magenta],
+            },
+        ]
+    }
+}
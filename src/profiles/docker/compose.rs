@@ -0,0 +1,117 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    // Both the compose v2 plugin and the v1 docker-compose tables
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "HEADERS",
+                regexp: LazyRegex::new(
+                    r#"(?:\s|^)(NAME|IMAGE|COMMAND|SERVICE|CREATED|STATUS|PORTS|Name|Command|State|Ports)(?:\s|$)"#,
+                ),
+                colors: vec![&Colors::Default, &Colors::UDefault],
+            },
+            Palette {
+                name: "Separator",
+                regexp: LazyRegex::new(r#"^-+$"#),
+                colors: vec![&Colors::BBlack],
+            },
+            // e.g. "myapp-web-1" or "myapp_web_1"
+            Palette {
+                name: "NAME",
+                regexp: LazyRegex::new(r#"^([\w.]+)([-_])([\w.\-]+?)([-_]\d+)\s"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Yellow,
+                    &Colors::Default,
+                    &Colors::OnBlue,
+                    &Colors::Default,
+                ],
+            },
+            Palette {
+                name: "COMMAND",
+                regexp: LazyRegex::new(r#"^\s+".*?"\s"#),
+                colors: vec![&Colors::BBlack],
+            },
+            Palette {
+                name: "Statuses - running",
+                regexp: LazyRegex::new(
+                    r#"(?:\s{2}|^)(?:running|Up)(?:\s(?:\d+|About an?|Less than a)\s\w+)*(?=\s{2}|$)"#,
+                ),
+                colors: vec![&Colors::BGreen],
+            },
+            Palette {
+                name: "Statuses - exited",
+                regexp: LazyRegex::new(r#"(?:\s{2}|^)(?:exited|Exit)\s\(?(?:(0)|(\d+))\)?"#),
+                colors: vec![&Colors::BRed, &Colors::Green, &Colors::BRed],
+            },
+            Palette {
+                name: "Statuses - restarting",
+                regexp: LazyRegex::new(r#"(?:\s{2}|^)(?:restarting|Restarting)(?:\s\(\d+\))?"#),
+                colors: vec![&Colors::BBlue],
+            },
+            Palette {
+                name: "Statuses - paused",
+                regexp: LazyRegex::new(r#"(?:\s{2}|^)(?:paused|Paused)(?=\s{2}|$)"#),
+                colors: vec![&Colors::BYellow],
+            },
+            Palette {
+                name: "Statuses - created",
+                regexp: LazyRegex::new(r#"(?:\s{2}|^)created(?=\s{2}|$)"#),
+                colors: vec![&Colors::Blue],
+            },
+            Palette {
+                name: "Health - healthy",
+                regexp: LazyRegex::new(r#"\s\(healthy\)"#),
+                colors: vec![&Colors::BGreen],
+            },
+            Palette {
+                name: "Health - starting",
+                regexp: LazyRegex::new(r#"\s\(health: starting\)"#),
+                colors: vec![&Colors::BYellow],
+            },
+            Palette {
+                name: "Health - unhealthy",
+                regexp: LazyRegex::new(r#"\s\(unhealthy\)"#),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "Ip Addresses",
+                regexp: LazyRegex::new(r#"(\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3})(\:)?"#),
+                colors: vec![&Colors::Default, &Colors::Blue, &Colors::Default],
+            },
+            Palette {
+                name: "Ports",
+                regexp: LazyRegex::new(
+                    r#"(\d{1,5})?(-)?(\d{1,5})?(->)?(\d{1,5})(-)?(\d{1,5})?(\/)(tcp|udp)"#,
+                ),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::BGreen,
+                    &Colors::Default,
+                    &Colors::BGreen,
+                    &Colors::Default,
+                    &Colors::BGreen,
+                    &Colors::Default,
+                    &Colors::BGreen,
+                    &Colors::Default,
+                    &Colors::Cyan,
+                ],
+            },
+        ]
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    // 2021-09-01T10:00:00.123456789+08:00 container start 3f4ab2a1c0de... (image=nginx, name=web)
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Timestamp",
+                regexp: LazyRegex::new(
+                    r#"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(?:\.\d+)?(?:Z|[+-]\d{2}:\d{2})\s"#,
+                ),
+                colors: vec![&Colors::BBlack],
+            },
+            Palette {
+                name: "Type",
+                regexp: LazyRegex::new(
+                    r#"^(?:container|image|volume|network|daemon|plugin|service|node|secret|config)\s"#,
+                ),
+                colors: vec![&Colors::Cyan],
+            },
+            // https://docs.docker.com/engine/reference/commandline/events/#object-types
+            Palette {
+                name: "Action - health",
+                regexp: LazyRegex::new(
+                    r#"^(health_status:\s)(?:(healthy)|(unhealthy)|(starting))\s"#,
+                ),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Yellow,
+                    &Colors::BGreen,
+                    &Colors::BRed,
+                    &Colors::BYellow,
+                ],
+            },
+            Palette {
+                name: "Action - created/started",
+                regexp: LazyRegex::new(
+                    r#"^(?:create|start|restart|unpause|connect|mount|pull|push|tag|import|load|save|commit|update|attach|reload|enable|install)\s"#,
+                ),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Action - killed",
+                regexp: LazyRegex::new(r#"^(?:die|kill|oom)\s"#),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "Action - stopped/removed",
+                regexp: LazyRegex::new(
+                    r#"^(?:stop|pause|destroy|delete|remove|prune|untag|disconnect|unmount|detach|disable)\s"#,
+                ),
+                colors: vec![&Colors::Red],
+            },
+            // exec_create, exec_start, exec_die, ...
+            Palette {
+                name: "Action - others",
+                regexp: LazyRegex::new(r#"^[a-z_]+(?::\s[^\s(]+)?\s"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "Object ID",
+                regexp: LazyRegex::new(r#"^\s?(?:sha256:)?[\da-f]{64}\s|^\s?[\w.\-/:@]+\s"#),
+                colors: vec![&Colors::BWhite],
+            },
+            Palette {
+                name: "exitCode",
+                regexp: LazyRegex::new(r#"(exitCode=)(?:(0)|(\d+))"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Green,
+                    &Colors::BRed,
+                ],
+            },
+            Palette {
+                name: "name",
+                regexp: LazyRegex::new(r#"(name=)([^,)]+)"#),
+                colors: vec![&Colors::Default, &Colors::Blue, &Colors::OnBlue],
+            },
+            Palette {
+                name: "Attributes",
+                regexp: LazyRegex::new(r#"([\w.\-/]+)(=)"#),
+                colors: vec![&Colors::Default, &Colors::Blue, &Colors::Default],
+            },
+        ]
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        let mut p = vec![
+            Palette {
+                name: "REPO, TAG, IMAGE ID",
+                regexp: LazyRegex::new(r#"^([a-z]+\/?[^\s]+)\s+([^\s]+)\s+(\w+)"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::BWhite,
+                    &Colors::BCyan,
+                    &Colors::BBlack,
+                ],
+            },
+            Palette {
+                name: "latest",
+                regexp: LazyRegex::new(r#"(?<=\s)latest(?=\s+)"#),
+                colors: vec![&Colors::DCyan],
+            },
+            Palette {
+                name: "REPOSITORY (Image name)",
+                regexp: LazyRegex::new(r#"^(?:(\S+)\/)*(\S+)\s"#),
+                colors: vec![&Colors::Default, &Colors::Yellow, &Colors::BWhite],
+            },
+            Palette {
+                name: "images without name",
+                regexp: LazyRegex::new(r#"^<none>.*$"#),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "images without tag",
+                regexp: LazyRegex::new(r#"\s+(<none>)\s+"#),
+                colors: vec![&Colors::UnChanged, &Colors::BRed],
+            },
+            Palette {
+                name: "Size 'K'",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+[.,]?\d*\s?(KB?|B)"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Size 'M', 2 digits",
+                regexp: LazyRegex::new(r#"(?<=\s)\d{1,2}[.,]?\d*\s?MB?"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Size 'M' 3+ digits",
+                regexp: LazyRegex::new(r#"(?<=\s)\d{3,4}[.,]?\d*\s?MB?"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "Size 'G'",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+[.,]?\d*\s?GB?"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "CREATED seconds/minutes",
+                regexp: LazyRegex::new(
+                    r#"[\da-f]{12}\s+((?:About a|\d+) (?:seconds?|minutes?) ago)"#,
+                ),
+                colors: vec![&Colors::Default, &Colors::OnGreen, &Colors::BWhite],
+            },
+            Palette {
+                name: "CREATED About a minute ago",
+                regexp: LazyRegex::new(r#"\s+(About a minute ago)\s\w+"#),
+                colors: vec![&Colors::Default, &Colors::OnGreen, &Colors::BWhite],
+            },
+            Palette {
+                name: "CREATED hours",
+                regexp: LazyRegex::new(r#"\s+(\d+\shours\s\w+)"#),
+                colors: vec![&Colors::Default, &Colors::BGreen],
+            },
+            Palette {
+                name: "CREATED days",
+                regexp: LazyRegex::new(r#"\s+(\d+\sdays\s\w+)"#),
+                colors: vec![&Colors::Default, &Colors::Green],
+            },
+            Palette {
+                name: "CREATED weeks",
+                regexp: LazyRegex::new(r#"\s+(\d+\sweeks\s\w+)"#),
+                colors: vec![&Colors::Default, &Colors::Yellow],
+            },
+            Palette {
+                name: "CREATED months",
+                regexp: LazyRegex::new(r#"\s+(\d+\smonths\s\w+)"#),
+                colors: vec![&Colors::Default, &Colors::Red],
+            },
+            Palette {
+                name: "HEADERS",
+                regexp: LazyRegex::new(r#"(?:\s|^)(REPOSITORY|TAG|IMAGE ID|CREATED|SIZE)(?:\s|$)"#),
+                colors: vec![&Colors::Default, &Colors::UDefault],
+            },
+        ];
+        p.reverse();
+        p
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Sections",
+                regexp: LazyRegex::new(r#"^(?:Client|Server):"#),
+                colors: vec![&Colors::UBWhite],
+            },
+            Palette {
+                name: "WARNING",
+                regexp: LazyRegex::new(r#"^WARNING:.*$"#),
+                colors: vec![&Colors::BYellow],
+            },
+            // e.g. "ERROR: Cannot connect to the Docker daemon at unix:///var/run/docker.sock"
+            Palette {
+                name: "ERROR",
+                regexp: LazyRegex::new(r#"^\s*(?:ERROR|Error|errors pretty printing info).*$"#),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "Containers - Running",
+                regexp: LazyRegex::new(r#"^\s+(Running:)\s+([1-9]\d*)$"#),
+                colors: vec![&Colors::Default, &Colors::Blue, &Colors::BGreen],
+            },
+            Palette {
+                name: "Containers - Paused",
+                regexp: LazyRegex::new(r#"^\s+(Paused:)\s+([1-9]\d*)$"#),
+                colors: vec![&Colors::Default, &Colors::Blue, &Colors::BYellow],
+            },
+            Palette {
+                name: "Containers - Stopped",
+                regexp: LazyRegex::new(r#"^\s+(Stopped:)\s+([1-9]\d*)$"#),
+                colors: vec![&Colors::Default, &Colors::Blue, &Colors::Red],
+            },
+            Palette {
+                name: "Server Version",
+                regexp: LazyRegex::new(r#"^\s+((?:Server\s)?Version:)\s+(\S+)$"#),
+                colors: vec![&Colors::Default, &Colors::Blue, &Colors::BGreen],
+            },
+            // e.g. "buildx: Build with BuildKit (Docker Inc., v0.6.1-docker)"
+            Palette {
+                name: "Plugins",
+                regexp: LazyRegex::new(r#"^\s+([\w\-]+:)\s(.+)\s(\(.+\))$"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BBlack,
+                ],
+            },
+            Palette {
+                name: "Keys",
+                regexp: LazyRegex::new(r#"^\s+([^:]+:)(?:\s|$)"#),
+                colors: vec![&Colors::Default, &Colors::Blue],
+            },
+            Palette {
+                name: "true",
+                regexp: LazyRegex::new(r#"(?<=\s)true$"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "false",
+                regexp: LazyRegex::new(r#"(?<=\s)false$"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "Numbers",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+$"#),
+                colors: vec![&Colors::Cyan],
+            },
+            Palette {
+                name: "Size",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+[.,]?\d*[KMGT]i?B$"#),
+                colors: vec![&Colors::Magenta],
+            },
+            Palette {
+                name: "Paths",
+                regexp: LazyRegex::new(r#"(?<=\s)(?:\/[\w.\-]+)+\/?$"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "Url",
+                regexp: LazyRegex::new(r#"\b(?:https?|unix|tcp)://\S+"#),
+                colors: vec![&Colors::UBlue],
+            },
+            Palette {
+                name: "Ip Addresses",
+                regexp: LazyRegex::new(
+                    r#"\b\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}(?:\/\d{1,2}|:\d{1,5})?\b"#,
+                ),
+                colors: vec![&Colors::Blue],
+            },
+        ]
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    // The output is indented JSON with one value per line, e.g.
+    //     "Status": "running",
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "State - running/healthy",
+                regexp: LazyRegex::new(
+                    r#"^\s*("(?:Status|Health)")(:\s)("(?:running|healthy)"),?$"#,
+                ),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BGreen,
+                ],
+            },
+            Palette {
+                name: "State - exited/dead/unhealthy",
+                regexp: LazyRegex::new(
+                    r#"^\s*("(?:Status|Health)")(:\s)("(?:exited|dead|unhealthy|removing)"),?$"#,
+                ),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BRed,
+                ],
+            },
+            Palette {
+                name: "State - OOMKilled",
+                regexp: LazyRegex::new(r#"^\s*("OOMKilled")(:\s)(true),?$"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BRed,
+                ],
+            },
+            Palette {
+                name: "State - ExitCode",
+                regexp: LazyRegex::new(r#"^\s*("ExitCode")(:\s)([1-9]\d*),?$"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BRed,
+                ],
+            },
+            Palette {
+                name: "JSON key",
+                regexp: LazyRegex::new(r#"^\s*("(?:[^"\\]|\\.)*")(?=:\s)"#),
+                colors: vec![&Colors::Default, &Colors::Blue],
+            },
+            Palette {
+                name: "JSON string",
+                regexp: LazyRegex::new(r#"^(?::\s|\s*)("(?:[^"\\]|\\.)*"),?$"#),
+                colors: vec![&Colors::Default, &Colors::Green],
+            },
+            Palette {
+                name: "JSON number",
+                regexp: LazyRegex::new(r#"^(?::\s|\s*)(-?\d+(?:\.\d+)?(?:[eE][+-]?\d+)?),?$"#),
+                colors: vec![&Colors::Default, &Colors::Cyan],
+            },
+            Palette {
+                name: "JSON boolean",
+                regexp: LazyRegex::new(r#"^(?::\s|\s*)(true|false),?$"#),
+                colors: vec![&Colors::Default, &Colors::Yellow],
+            },
+            Palette {
+                name: "JSON null",
+                regexp: LazyRegex::new(r#"^(?::\s|\s*)(null),?$"#),
+                colors: vec![&Colors::Default, &Colors::BBlack],
+            },
+            Palette {
+                name: "JSON brackets",
+                regexp: LazyRegex::new(r#"[\[\]{}]"#),
+                colors: vec![&Colors::BWhite],
+            },
+            // Not found, e.g. "Error: No such object: web"
+            Palette {
+                name: "Error",
+                regexp: LazyRegex::new(r#"^Error(?::|\sresponse from daemon:).*$"#),
+                colors: vec![&Colors::BRed],
+            },
+        ]
+    }
+}
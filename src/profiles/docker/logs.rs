@@ -0,0 +1,104 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    // The logs are whatever the container prints, only the common parts are colored
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            // docker compose logs, e.g. "web_1  | "
+            Palette {
+                name: "Compose service",
+                regexp: LazyRegex::new(r#"^([\w.\-]+)(\s+\|\s)"#),
+                colors: vec![&Colors::Default, &Colors::Cyan, &Colors::BBlack],
+            },
+            // --timestamps
+            Palette {
+                name: "Timestamp",
+                regexp: LazyRegex::new(
+                    r#"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(?:\.\d+)?(?:Z|[+-]\d{2}:\d{2})\s"#,
+                ),
+                colors: vec![&Colors::BBlack],
+            },
+            Palette {
+                name: "Level - error",
+                regexp: LazyRegex::new(
+                    r#"(?i)\b(?:error|err|fatal|crit(?:ical)?|panic|emerg(?:ency)?|alert)\b"#,
+                ),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "Level - warning",
+                regexp: LazyRegex::new(r#"(?i)\bwarn(?:ing)?\b"#),
+                colors: vec![&Colors::BYellow],
+            },
+            Palette {
+                name: "Level - info",
+                regexp: LazyRegex::new(r#"\b(?:INFO|NOTICE|info|notice)\b"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Level - debug",
+                regexp: LazyRegex::new(r#"\b(?:DEBUG|TRACE|debug|trace)\b"#),
+                colors: vec![&Colors::BBlack],
+            },
+            // Access logs, e.g. "GET /index.html HTTP/1.1" 200
+            Palette {
+                name: "HTTP request",
+                regexp: LazyRegex::new(
+                    r#"\b(GET|HEAD|POST|PUT|DELETE|PATCH|OPTIONS|CONNECT|TRACE)\s(\S+)(\sHTTP/[\d.]+)?"#,
+                ),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::BMagenta,
+                    &Colors::White,
+                    &Colors::Default,
+                ],
+            },
+            Palette {
+                name: "HTTP status 2xx/3xx",
+                regexp: LazyRegex::new(r#"(?<=["\s])[23]\d{2}(?=\s)"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "HTTP status 4xx",
+                regexp: LazyRegex::new(r#"(?<=["\s])4\d{2}(?=\s)"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "HTTP status 5xx",
+                regexp: LazyRegex::new(r#"(?<=["\s])5\d{2}(?=\s)"#),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "Ip Addresses",
+                regexp: LazyRegex::new(r#"\b\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}(?::\d{1,5})?\b"#),
+                colors: vec![&Colors::Blue],
+            },
+            Palette {
+                name: "Url",
+                regexp: LazyRegex::new(r#"\b(?:https?|wss?|tcp|unix)://\S+"#),
+                colors: vec![&Colors::UBlue],
+            },
+            Palette {
+                name: "Date and time",
+                regexp: LazyRegex::new(
+                    r#"\d{4}[-/]\d{2}[-/]\d{2}[T\s]\d{2}:\d{2}:\d{2}(?:[.,]\d+)?(?:Z|[+-]\d{2}:?\d{2})?|\d{2}/\w{3}/\d{4}:\d{2}:\d{2}:\d{2}\s[+-]\d{4}"#,
+                ),
+                colors: vec![&Colors::BBlack],
+            },
+        ]
+    }
+}
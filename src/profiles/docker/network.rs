@@ -0,0 +1,84 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "HEADERS",
+                regexp: LazyRegex::new(r#"^(NETWORK ID|ID)\s+(NAME)\s+(DRIVER)\s+(SCOPE)"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::UDefault,
+                    &Colors::UDefault,
+                    &Colors::UDefault,
+                    &Colors::UDefault,
+                ],
+            },
+            Palette {
+                name: "NETWORK ID",
+                regexp: LazyRegex::new(r#"^[\da-f]{12}(?:[\da-f]{52})?\s"#),
+                colors: vec![&Colors::BBlack],
+            },
+            // Built-in networks, the ones which can't be removed
+            Palette {
+                name: "NAME - predefined",
+                regexp: LazyRegex::new(r#"(?<=\s)(?:bridge|host|none|podman)(?=\s+\S+\s+\S+$)"#),
+                colors: vec![&Colors::Cyan],
+            },
+            Palette {
+                name: "NAME",
+                regexp: LazyRegex::new(r#"(?<=\s)([\w.\-]+)(?=\s+\S+\s+\S+$)"#),
+                colors: vec![&Colors::Default, &Colors::BWhite],
+            },
+            Palette {
+                name: "DRIVER - bridge",
+                regexp: LazyRegex::new(r#"(?<=\s)bridge(?=\s+\S+$)"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "DRIVER - host",
+                regexp: LazyRegex::new(r#"(?<=\s)host(?=\s+\S+$)"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "DRIVER - overlay",
+                regexp: LazyRegex::new(r#"(?<=\s)overlay(?=\s+\S+$)"#),
+                colors: vec![&Colors::Blue],
+            },
+            Palette {
+                name: "DRIVER - macvlan/ipvlan",
+                regexp: LazyRegex::new(r#"(?<=\s)(?:macvlan|ipvlan)(?=\s+\S+$)"#),
+                colors: vec![&Colors::Magenta],
+            },
+            Palette {
+                name: "DRIVER - null",
+                regexp: LazyRegex::new(r#"(?<=\s)null(?=\s+\S+$)"#),
+                colors: vec![&Colors::BBlack],
+            },
+            Palette {
+                name: "SCOPE - local",
+                regexp: LazyRegex::new(r#"(?<=\s)local$"#),
+                colors: vec![&Colors::DCyan],
+            },
+            Palette {
+                name: "SCOPE - swarm/global",
+                regexp: LazyRegex::new(r#"(?<=\s)(?:swarm|global)$"#),
+                colors: vec![&Colors::BBlue],
+            },
+        ]
+    }
+}
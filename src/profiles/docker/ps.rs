@@ -0,0 +1,198 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "HEADERS",
+                regexp: LazyRegex::new(
+                    r#"(?:\s|^)(CONTAINER ID|IMAGE|COMMAND|CREATED|STATUS|PORTS|NAMES|POD ID|PODNAME|IS INFRA)(?:\s|$)"#,
+                ),
+                colors: vec![&Colors::Default, &Colors::UDefault],
+            },
+            Palette {
+                name: "IMAGE NAME (as docker image)",
+                regexp: LazyRegex::new(
+                    r#"\s{2,}(?:([a-z\-_0-9.]+)\/)*([a-z\-_0-9]+)(:\S+)?\s{2,}\""#,
+                ),
+                colors: vec![
+                    &Colors::UnChanged,
+                    &Colors::Yellow,
+                    &Colors::BWhite,
+                    &Colors::Cyan,
+                ],
+            },
+            // podman doesn't quote the command and prefixes the registry, e.g. docker.io/library/nginx
+            Palette {
+                name: "IMAGE NAME (as podman image)",
+                regexp: LazyRegex::new(
+                    r#"^[\da-f]{12}\s{2,}(?:([a-z\-_0-9.]+)\/)*([a-z\-_0-9]+)(:\S+)?\s{2,}"#,
+                ),
+                colors: vec![
+                    &Colors::UnChanged,
+                    &Colors::Yellow,
+                    &Colors::BWhite,
+                    &Colors::Cyan,
+                ],
+            },
+            Palette {
+                name: "IMAGE",
+                regexp: LazyRegex::new(
+                    r#"^(?!CONTAINER)(\w+)\s+([^\s]+)\s+(".*")\s+(.*(?=(?:Up|Exited|Created|Restarting|Paused|Stopped|Configured|Initialized)))"#,
+                ),
+                colors: vec![
+                    &Colors::UnChanged,
+                    &Colors::BBlack,
+                    &Colors::UnChanged,
+                    &Colors::BBlack,
+                    &Colors::Cyan,
+                ],
+            },
+            Palette {
+                name: "Statuses - Created",
+                regexp: LazyRegex::new(r#"\sCreated\s"#),
+                colors: vec![&Colors::Blue],
+            },
+            // https://github.com/docker/docker/blob/e5a3f86e447dd659da3c2e759f3c088a0bfcfe3d/container/state.go#L40
+            Palette {
+                name: "Statuses - Up",
+                regexp: LazyRegex::new(r#"(?:\s{2}|^)(?:Up|Restarting)(?:(?:\s[\w,\d]+)+)?"#),
+                colors: vec![&Colors::BGreen],
+            },
+            // podman
+            Palette {
+                name: "Statuses - Configured/Initialized",
+                regexp: LazyRegex::new(r#"\s(?:Configured|Initialized)\s"#),
+                colors: vec![&Colors::Blue],
+            },
+            Palette {
+                name: "Statuses - Paused",
+                regexp: LazyRegex::new(r#"(?:\s{2}|^)Paused(?=\s{2}|$)"#),
+                colors: vec![&Colors::BYellow],
+            },
+            Palette {
+                name: "Statuses - Stopped",
+                regexp: LazyRegex::new(r#"(?:\s{2}|^)Stopp(?:ed|ing)(?=\s{2}|$)"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Health - healthy",
+                regexp: LazyRegex::new(r#"\s\(healthy\)"#),
+                colors: vec![&Colors::BGreen],
+            },
+            Palette {
+                name: "Health - starting",
+                regexp: LazyRegex::new(r#"\s\(health: starting\)"#),
+                colors: vec![&Colors::BYellow],
+            },
+            Palette {
+                name: "Health - unhealthy",
+                regexp: LazyRegex::new(r#"\s\(unhealthy\)"#),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "Statuses - Exited",
+                regexp: LazyRegex::new(r#"Exited\s.\d+."#),
+                colors: vec![&Colors::BRed, &Colors::Red],
+            },
+            Palette {
+                name: "Statuses - Restarting",
+                regexp: LazyRegex::new(r#"Restarting\s.\d+."#),
+                colors: vec![&Colors::BBlue],
+            },
+            Palette {
+                name: "CREATED seconds",
+                regexp: LazyRegex::new(r#"\s+(\d+\sseconds?\s\w+)"#),
+                colors: vec![&Colors::UnChanged, &Colors::UnChanged, &Colors::UnChanged],
+            },
+            Palette {
+                name: "CREATED About a minute ago",
+                regexp: LazyRegex::new(r#"\s+(About a minute ago)\s"#),
+                colors: vec![&Colors::UnChanged, &Colors::UnChanged, &Colors::UnChanged],
+            },
+            Palette {
+                name: "CREATED minutes",
+                regexp: LazyRegex::new(r#"\s+(\d+\sminutes\s\w+)"#),
+                colors: vec![&Colors::UnChanged, &Colors::UnChanged, &Colors::UnChanged],
+            },
+            Palette {
+                name: "CREATED hours",
+                regexp: LazyRegex::new(r#"\s+(\d+\shours?\s\w+)"#),
+                colors: vec![&Colors::UnChanged, &Colors::UnChanged],
+            },
+            Palette {
+                name: "CREATED days",
+                regexp: LazyRegex::new(r#"\s+(\d+\sdays?\s\w+)"#),
+                colors: vec![&Colors::UnChanged, &Colors::UnChanged],
+            },
+            Palette {
+                name: "CREATED weeks",
+                regexp: LazyRegex::new(r#"\s+(\d+\sweeks?\s\w+)"#),
+                colors: vec![&Colors::UnChanged, &Colors::UnChanged],
+            },
+            Palette {
+                name: "CREATED months",
+                regexp: LazyRegex::new(r#"\s+(\d+\smonths?\s\w+)"#),
+                colors: vec![&Colors::UnChanged, &Colors::UnChanged],
+            },
+            // podman ps --pod
+            Palette {
+                name: "POD ID",
+                regexp: LazyRegex::new(r#"(?<=\s\s)[\da-f]{12}(?=\s\s)"#),
+                colors: vec![&Colors::DCyan],
+            },
+            Palette {
+                name: "IS INFRA",
+                regexp: LazyRegex::new(r#"(?<=\s\s)true(?=\s\s|$)"#),
+                colors: vec![&Colors::Magenta],
+            },
+            Palette {
+                name: "Ip Addresses",
+                regexp: LazyRegex::new(r#"(\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3})(\:)?"#),
+                colors: vec![&Colors::Default, &Colors::Blue, &Colors::Default],
+            },
+            Palette {
+                name: "Ports",
+                regexp: LazyRegex::new(
+                    r#"(\d{1,5})?(-)?(\d{1,5})?(->)?(\d{1,5})(-)?(\d{1,5})?(\/)(tcp|udp)"#,
+                ),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::BGreen,
+                    &Colors::Default,
+                    &Colors::BGreen,
+                    &Colors::Default,
+                    &Colors::BGreen,
+                    &Colors::Default,
+                    &Colors::BGreen,
+                    &Colors::Default,
+                    &Colors::Cyan,
+                ],
+            },
+            Palette {
+                name: "NAMES",
+                regexp: LazyRegex::new(r#"(?:([a-z\-_0-9]+)\/)*([a-z\-_0-9]+)$"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Yellow,
+                    &Colors::OnBlue,
+                    &Colors::White,
+                ],
+            },
+        ]
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            // The stream redraws the screen before the headers
+            Palette {
+                name: "HEADERS",
+                regexp: LazyRegex::new(
+                    r#"(?:\s|^)(CONTAINER ID|CONTAINER|ID|NAME|CPU %|MEM USAGE / LIMIT|MEM %|NET I/O|BLOCK I/O|PIDS)(?:\s|$)"#,
+                ),
+                colors: vec![&Colors::Default, &Colors::UDefault],
+            },
+            Palette {
+                name: "CONTAINER ID",
+                regexp: LazyRegex::new(r#"^([\da-f]{12}(?:[\da-f]{52})?)\s+(\S+)\s"#),
+                colors: vec![&Colors::Default, &Colors::BBlack, &Colors::OnBlue],
+            },
+            // CPU % may exceed 100% with several cores, it's followed by MEM USAGE / LIMIT and MEM %
+            Palette {
+                name: "CPU % 0-49%",
+                regexp: LazyRegex::new(r#"^\s+([1-4]?\d\.\d+%)(?=\s+\S+\s/\s\S+\s+[\d.]+%)"#),
+                colors: vec![&Colors::Default, &Colors::Green],
+            },
+            Palette {
+                name: "CPU % 50-79%",
+                regexp: LazyRegex::new(r#"^\s+([5-7]\d\.\d+%)(?=\s+\S+\s/\s\S+\s+[\d.]+%)"#),
+                colors: vec![&Colors::Default, &Colors::Yellow],
+            },
+            Palette {
+                name: "CPU % 80-99%",
+                regexp: LazyRegex::new(r#"^\s+([89]\d\.\d+%)(?=\s+\S+\s/\s\S+\s+[\d.]+%)"#),
+                colors: vec![&Colors::Default, &Colors::Red],
+            },
+            Palette {
+                name: "CPU % 100%+",
+                regexp: LazyRegex::new(r#"^\s+(\d{3,}\.\d+%)(?=\s+\S+\s/\s\S+\s+[\d.]+%)"#),
+                colors: vec![&Colors::Default, &Colors::BRed],
+            },
+            Palette {
+                name: "MEM USAGE / LIMIT",
+                regexp: LazyRegex::new(
+                    r#"^\s+(\d+[.,]?\d*[KMGT]?i?B)(\s/\s)(\d+[.,]?\d*[KMGT]?i?B)\s"#,
+                ),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::BWhite,
+                    &Colors::Default,
+                    &Colors::White,
+                ],
+            },
+            // MEM % is followed by NET I/O and BLOCK I/O
+            Palette {
+                name: "MEM % 0-49%",
+                regexp: LazyRegex::new(r#"^\s+([1-4]?\d\.\d+%)(?=\s+\S+\s/\s\S+\s+\S+\s/\s)"#),
+                colors: vec![&Colors::Default, &Colors::Green],
+            },
+            Palette {
+                name: "MEM % 50-79%",
+                regexp: LazyRegex::new(r#"^\s+([5-7]\d\.\d+%)(?=\s+\S+\s/\s\S+\s+\S+\s/\s)"#),
+                colors: vec![&Colors::Default, &Colors::Yellow],
+            },
+            Palette {
+                name: "MEM % 80-89%",
+                regexp: LazyRegex::new(r#"^\s+(8\d\.\d+%)(?=\s+\S+\s/\s\S+\s+\S+\s/\s)"#),
+                colors: vec![&Colors::Default, &Colors::Red],
+            },
+            Palette {
+                name: "MEM % 90-100%",
+                regexp: LazyRegex::new(r#"^\s+((?:9\d|100)\.\d+%)(?=\s+\S+\s/\s\S+\s+\S+\s/\s)"#),
+                colors: vec![&Colors::Default, &Colors::BRed],
+            },
+            Palette {
+                name: "NET I/O, BLOCK I/O",
+                regexp: LazyRegex::new(
+                    r#"(\d+[.,]?\d*[kKMGT]?i?B)(\s/\s)(\d+[.,]?\d*[kKMGT]?i?B)"#,
+                ),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Cyan,
+                    &Colors::Default,
+                    &Colors::Magenta,
+                ],
+            },
+            Palette {
+                name: "PIDS",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+$"#),
+                colors: vec![&Colors::BBlue],
+            },
+            // Stopped containers with --all
+            Palette {
+                name: "No data",
+                regexp: LazyRegex::new(r#"--"#),
+                colors: vec![&Colors::BBlack],
+            },
+        ]
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "HEADERS",
+                regexp: LazyRegex::new(
+                    r#"(?:\s|^)(TYPE|TOTAL|ACTIVE|SIZE|RECLAIMABLE|REPOSITORY|TAG|IMAGE ID|CREATED|SHARED SIZE|UNIQUE SIZE|CONTAINERS|CONTAINER ID|IMAGE|COMMAND|LOCAL VOLUMES|STATUS|NAMES|VOLUME NAME|LINKS|CACHE ID|CACHE TYPE|LAST USED|USAGE|SHARED)(?:\s|$)"#,
+                ),
+                colors: vec![&Colors::Default, &Colors::UDefault],
+            },
+            // --verbose
+            Palette {
+                name: "Sections",
+                regexp: LazyRegex::new(r#"^[\w ]+ space usage:$"#),
+                colors: vec![&Colors::UBWhite],
+            },
+            Palette {
+                name: "TYPE",
+                regexp: LazyRegex::new(r#"^(?:Images|Containers|Local Volumes|Build Cache)\s"#),
+                colors: vec![&Colors::BWhite],
+            },
+            Palette {
+                name: "RECLAIMABLE 0-29%",
+                regexp: LazyRegex::new(r#"\((?:[12]?\d)%\)$"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "RECLAIMABLE 30-59%",
+                regexp: LazyRegex::new(r#"\((?:[3-5]\d)%\)$"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "RECLAIMABLE 60-100%",
+                regexp: LazyRegex::new(r#"\((?:[6-9]\d|100)%\)$"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Size 'B', 'kB'",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+[.,]?\d*k?B(?=\s|$)"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Size 'MB'",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+[.,]?\d*MB(?=\s|$)"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "Size 'GB', 'TB'",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+[.,]?\d*[GT]B(?=\s|$)"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Counts",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+(?=\s)"#),
+                colors: vec![&Colors::Cyan],
+            },
+        ]
+    }
+}
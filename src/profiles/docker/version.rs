@@ -0,0 +1,75 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            // e.g. "Server: Docker Engine - Community"
+            Palette {
+                name: "Sections",
+                regexp: LazyRegex::new(r#"^(Client|Server):(.*)$"#),
+                colors: vec![&Colors::Default, &Colors::UBWhite, &Colors::BWhite],
+            },
+            // e.g. " Engine:" or " containerd:"
+            Palette {
+                name: "Components",
+                regexp: LazyRegex::new(r#"^\s([\w\-]+(?:\s[\w\-]+)*):$"#),
+                colors: vec![&Colors::Default, &Colors::Cyan],
+            },
+            Palette {
+                name: "Version",
+                regexp: LazyRegex::new(r#"^\s+(Version:)\s+(\S+)$"#),
+                colors: vec![&Colors::Default, &Colors::Blue, &Colors::BGreen],
+            },
+            Palette {
+                name: "API version",
+                regexp: LazyRegex::new(r#"^\s+(API version:)\s+([\d.]+)(?:\s(\(.+\)))?$"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Green,
+                    &Colors::BBlack,
+                ],
+            },
+            Palette {
+                name: "Git commit",
+                regexp: LazyRegex::new(r#"^\s+(Git ?[Cc]ommit:)\s+(\S+)$"#),
+                colors: vec![&Colors::Default, &Colors::Blue, &Colors::Yellow],
+            },
+            Palette {
+                name: "Keys",
+                regexp: LazyRegex::new(r#"^\s+([^:]+:)\s"#),
+                colors: vec![&Colors::Default, &Colors::Blue],
+            },
+            Palette {
+                name: "true",
+                regexp: LazyRegex::new(r#"(?<=\s)true$"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "false",
+                regexp: LazyRegex::new(r#"(?<=\s)false$"#),
+                colors: vec![&Colors::Yellow],
+            },
+            // Client only, e.g. "Cannot connect to the Docker daemon at unix:///var/run/docker.sock"
+            Palette {
+                name: "Cannot connect",
+                regexp: LazyRegex::new(r#"^(?:Cannot connect|Error response from daemon).*$"#),
+                colors: vec![&Colors::BRed],
+            },
+        ]
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "HEADERS",
+                regexp: LazyRegex::new(r#"^(DRIVER)\s+(VOLUME NAME)$"#),
+                colors: vec![&Colors::Default, &Colors::UDefault, &Colors::UDefault],
+            },
+            Palette {
+                name: "DRIVER - local",
+                regexp: LazyRegex::new(r#"^local\s"#),
+                colors: vec![&Colors::Green],
+            },
+            // Plugins, e.g. "rexray/ebs" or "vieux/sshfs:latest"
+            Palette {
+                name: "DRIVER - plugin",
+                regexp: LazyRegex::new(r#"^\S+\s"#),
+                colors: vec![&Colors::Cyan],
+            },
+            // Created by "-v /data" or an image VOLUME without a name
+            Palette {
+                name: "VOLUME NAME - anonymous",
+                regexp: LazyRegex::new(r#"(?<=\s)[\da-f]{64}$"#),
+                colors: vec![&Colors::BBlack],
+            },
+            // Created by compose as <project>_<volume>
+            Palette {
+                name: "VOLUME NAME - compose",
+                regexp: LazyRegex::new(r#"(?<=\s)([\w.\-]+?)(_)([\w.\-]+)$"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Yellow,
+                    &Colors::Default,
+                    &Colors::BWhite,
+                ],
+            },
+            Palette {
+                name: "VOLUME NAME",
+                regexp: LazyRegex::new(r#"(?<=\s)[\w.\-]+$"#),
+                colors: vec![&Colors::BWhite],
+            },
+        ]
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Path",
+                regexp: LazyRegex::new(r#"\s+[\./]+([\w\s\-\_\.]+)(/.*)?$"#),
+                colors: vec![&Colors::Default, &Colors::BBlue, &Colors::Blue],
+            },
+            Palette {
+                name: "Total",
+                regexp: LazyRegex::new(r#"(.*)\s+(total)$"#),
+                colors: vec![&Colors::BYellow],
+            },
+            Palette {
+                name: "Size 'T'",
+                regexp: LazyRegex::new(r#"^ ?\d*[.,]?\dTi?"#),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "Size 'G'",
+                regexp: LazyRegex::new(r#"^ ?\d*[.,]?\dGi?"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Size 'G' in blocks",
+                regexp: LazyRegex::new(r#"^\d{7,9}"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Size 'M'",
+                regexp: LazyRegex::new(r#"^ ?\d*[.,]?\dMi?"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "Size 'M' in blocks",
+                regexp: LazyRegex::new(r#"^\d{4,6}"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "Size 'K'",
+                regexp: LazyRegex::new(r#"^ ?\d*[.,]?\dKi?"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Size 'K' in blocks",
+                regexp: LazyRegex::new(r#"^\d{1,3}"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Cannot read error",
+                regexp: LazyRegex::new(r#"^du.*"#),
+                colors: vec![&Colors::Red],
+            },
+        ]
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![Palette {
+            name: "Main",
+            regexp: LazyRegex::new(r#"^([^=]+)(=)(.*)$"#),
+            colors: vec![
+                &Colors::Default,
+                &Colors::Cyan,
+                &Colors::White,
+                &Colors::Yellow,
+            ],
+        }]
+    }
+}
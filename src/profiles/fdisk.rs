@@ -0,0 +1,72 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Size 'G'",
+                regexp: LazyRegex::new(r#"\s\d+[.,]?\d*\s?Gi?B?"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Size 'M'",
+                regexp: LazyRegex::new(r#"\s\d*[.,]?\d*\s?Mi?B?"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "Size 'K'",
+                regexp: LazyRegex::new(r#"\s\d*[.,]?\d*\s?Ki?B?"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "ID",
+                regexp: LazyRegex::new(r#"identifier: (.*)$"#),
+                colors: vec![&Colors::UnChanged, &Colors::Cyan],
+            },
+            Palette {
+                name: "Type",
+                regexp: LazyRegex::new(r#"type: (.*)$"#),
+                colors: vec![&Colors::UnChanged, &Colors::BCyan],
+            },
+            Palette {
+                name: "Partitions",
+                regexp: LazyRegex::new(r#"^(?:\/([^\/: ]+))+"#),
+                colors: vec![&Colors::Green, &Colors::BGreen],
+            },
+            Palette {
+                name: "Boot?",
+                regexp: LazyRegex::new(r#"\*\s\s\s"#),
+                colors: vec![&Colors::OnRed, &Colors::BWhite],
+            },
+            Palette {
+                name: "Disk",
+                regexp: LazyRegex::new(r#"^(Disk) (?:\/([^\/: ]+))+"#),
+                colors: vec![
+                    &Colors::Yellow,
+                    &Colors::OnYellow,
+                    &Colors::BYellow,
+                    &Colors::BYellow,
+                ],
+            },
+            Palette {
+                name: "Error",
+                regexp: LazyRegex::new(r#"fdisk: cannot open ([^:]+).*$"#),
+                colors: vec![&Colors::Red, &Colors::BRed],
+            },
+        ]
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "MS Types",
+                regexp: LazyRegex::new(r#"\b(fat|vfat|ntfs|msdos)\b"#),
+                colors: vec![&Colors::OnCyan],
+            },
+            Palette {
+                name: "Common Types",
+                regexp: LazyRegex::new(r#"\b(ext\d|xfs|btrfs|nfs)\b"#),
+                colors: vec![&Colors::Cyan],
+            },
+            // Like comment, leave at end always
+            Palette {
+                name: "cgroup and tmpfs lines",
+                regexp: LazyRegex::new(r#"^.*(?=cgroup|tmpfs).*$"#),
+                colors: vec![&Colors::BBlack],
+            },
+            Palette {
+                name: "RO",
+                regexp: LazyRegex::new(r#"(?:\s)ro"#),
+                colors: vec![&Colors::BGreen],
+            },
+            Palette {
+                name: "RW",
+                regexp: LazyRegex::new(r#"(?:\s)rw"#),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "Mount Path",
+                regexp: LazyRegex::new(r#"(?<=─|-)(?:\/([^\/ ]+))+"#),
+                colors: vec![&Colors::UnChanged, &Colors::BYellow],
+            },
+            Palette {
+                name: "Devices",
+                regexp: LazyRegex::new(r#"\s\/dev(?:\/([^\/ ]+))+"#),
+                colors: vec![&Colors::Green, &Colors::BGreen],
+            },
+        ]
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    // One line per target and reply, e.g.
+    //
+    //     1.1.1.1 : [0], 64 bytes, 4.51 ms (4.51 avg, 0% loss)
+    //     1.1.1.1 : xmt/rcv/%loss = 3/3/0%, min/avg/max = 4.41/4.50/4.58
+    //     1.1.1.1 : 4.51 4.41 -
+    //
+    // the last one of -C lists the latencies, "-" for a lost one. -s prints totals at the end
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Alive",
+                regexp: LazyRegex::new(r#"\bis alive\b"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Unreachable",
+                regexp: LazyRegex::new(
+                    r#"\bis unreachable\b|\baddress not found\b|\bName or service not known\b|ICMP \w+ Unreachable"#,
+                ),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Target",
+                regexp: LazyRegex::new(r#"^(\S+)\s+:"#),
+                colors: vec![&Colors::Default, &Colors::Blue],
+            },
+            Palette {
+                name: "No loss",
+                regexp: LazyRegex::new(r#"\b0% loss|/0%"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "All lost",
+                regexp: LazyRegex::new(r#"\b100% loss|/100%"#),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "Loss",
+                regexp: LazyRegex::new(r#"\b\d+% loss|/\d+%"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Sent/received",
+                regexp: LazyRegex::new(r#"xmt/rcv/%loss = (\d+)/(\d+)"#),
+                colors: vec![&Colors::Default, &Colors::BYellow, &Colors::BBlue],
+            },
+            Palette {
+                name: "min/avg/max",
+                regexp: LazyRegex::new(r#"min/avg/max = ([0-9.]+)/([0-9.]+)/([0-9.]+)"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::BYellow,
+                    &Colors::BBlue,
+                    &Colors::BRed,
+                ],
+            },
+            Palette {
+                name: "Sequence",
+                regexp: LazyRegex::new(r#"\[(\d+)\]"#),
+                colors: vec![&Colors::Default, &Colors::Magenta],
+            },
+            // Gauges of the latencies: a reply, a latency of -C or a round trip time of -s
+            Palette {
+                name: "Latency",
+                regexp: LazyRegex::new(r#"(?P<ms>[0-9.]+) ms(?= \()"#),
+                colors: vec![&Colors::Green, &Colors::Yellow, &Colors::Red, &Colors::BRed],
+            },
+            Palette {
+                name: "Latencies of -C",
+                regexp: LazyRegex::new(r#"(?<=\s)(?P<ms>\d+\.\d+)(?=\s[\d-]|$)"#),
+                colors: vec![&Colors::Green, &Colors::Yellow, &Colors::Red, &Colors::BRed],
+            },
+            Palette {
+                name: "Lost of -C",
+                regexp: LazyRegex::new(r#"(?<=\s)-(?=\s|$)"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Failures of -s",
+                regexp: LazyRegex::new(
+                    r#"^\s*([1-9]\d*) (?:unreachable|unknown addresses|timeouts|ICMP Host Unreachables)"#,
+                ),
+                colors: vec![&Colors::Red, &Colors::BRed],
+            },
+            Palette {
+                name: "Counts of -s",
+                regexp: LazyRegex::new(r#"^\s*(\d+) (?=[a-zA-Z])"#),
+                colors: vec![&Colors::Default, &Colors::BBlue],
+            },
+            Palette {
+                name: "IP",
+                regexp: LazyRegex::new(r#"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}"#),
+                colors: vec![&Colors::BBlue],
+            },
+        ]
+    }
+}
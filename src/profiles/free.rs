@@ -0,0 +1,52 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette("kib");
+    static ref BYTES_PALETTES: Vec<Palette<'static>> = Cmd::palette("bytes");
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    pub fn bytes_palettes() -> &'static [Palette<'static>] {
+        &BYTES_PALETTES
+    }
+
+    // The sizes are a gauge by bytes (1M, 1G and 1T), `unit` is the group of the gauge which
+    // tells what a number without a suffix counts: "kib" by default or "bytes" with -b
+    fn palette(unit: &str) -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Zero",
+                regexp: LazyRegex::new(r#"\s+0\w?(\s|$)"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Swap",
+                regexp: LazyRegex::new(r#"^Swap"#),
+                colors: vec![&Colors::BMagenta],
+            },
+            Palette {
+                name: "Mem",
+                regexp: LazyRegex::new(r#"^Mem"#),
+                colors: vec![&Colors::BCyan],
+            },
+            Palette {
+                name: "Size",
+                regexp: LazyRegex::from_string(format!(
+                    r#"(?<=\s)(?P<{}>\d+(?:[.,]\d+)?(?:[KMGTPB]i?)?)(?=\s|$)"#,
+                    unit
+                )),
+                colors: vec![&Colors::Green, &Colors::Yellow, &Colors::Red, &Colors::BRed],
+            },
+        ]
+    }
+}
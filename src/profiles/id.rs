@@ -0,0 +1,43 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "SELinux",
+                regexp: LazyRegex::new(r#"(\w+_u):(\w+_r):(\w+_t):([\w\-.:]+)"#),
+                colors: vec![
+                    &Colors::UnChanged,
+                    &Colors::Green,
+                    &Colors::Yellow,
+                    &Colors::Cyan,
+                    &Colors::Magenta,
+                ],
+            },
+            Palette {
+                name: "User",
+                regexp: LazyRegex::new(r#"uid.(\d+)\((\w+)\)"#),
+                colors: vec![&Colors::UnChanged, &Colors::Green, &Colors::BGreen],
+            },
+            Palette {
+                name: "Groups",
+                regexp: LazyRegex::new(r#"(\d+)\((\w+)\)"#),
+                colors: vec![&Colors::UnChanged, &Colors::Yellow, &Colors::BYellow],
+            },
+        ]
+    }
+}
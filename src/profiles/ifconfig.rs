@@ -0,0 +1,92 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "collisions",
+                regexp: LazyRegex::new(r#"collisions[\s|\:]\d+"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "carrier",
+                regexp: LazyRegex::new(r#"carrier[\s|\:]\d+"#),
+                colors: vec![&Colors::Cyan],
+            },
+            Palette {
+                name: "frame",
+                regexp: LazyRegex::new(r#"frame[\s|\:]\d+"#),
+                colors: vec![&Colors::White],
+            },
+            Palette {
+                name: "overruns",
+                regexp: LazyRegex::new(r#"overruns[\s|\:]\d+"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "dropped",
+                regexp: LazyRegex::new(r#"dropped[\s|\:]\d+"#),
+                colors: vec![&Colors::White],
+            },
+            Palette {
+                name: "errors",
+                regexp: LazyRegex::new(r#"errors[\s|\:]\d+"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "mtu",
+                regexp: LazyRegex::new(r#"(?i)mtu[\s|\:]\d+"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "flags",
+                regexp: LazyRegex::new(r#"(?<=[,<])[^,]+?(?=[,>])"#),
+                colors: vec![&Colors::Blue],
+            },
+            Palette {
+                name: "ip disc",
+                regexp: LazyRegex::new(r#"(inet6?|netmask|broadcast)"#),
+                colors: vec![&Colors::Cyan],
+            },
+            Palette {
+                name: "interface",
+                regexp: LazyRegex::new(r#"^([a-z0-9.]{2,}\d*):?\s"#),
+                colors: vec![&Colors::BGreen],
+            },
+            Palette {
+                name: "size",
+                regexp: LazyRegex::new(r#"\d+\.?\d*\s+[T|G|M|K]?i?B"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "hwaddr",
+                regexp: LazyRegex::new(r#"[\d[a-f]]{2}(\:[\d[a-f]]{2}){5}"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "ipv6",
+                regexp: LazyRegex::new(r#"\b[0-9a-fA-F]{1,4}(\:\:?[0-9a-fA-F]{1,4})+"#),
+                colors: vec![&Colors::BGreen],
+            },
+            Palette {
+                name: "ipv4",
+                regexp: LazyRegex::new(r#"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}"#),
+                colors: vec![&Colors::BGreen],
+            },
+        ]
+    }
+}
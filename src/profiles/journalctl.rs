@@ -0,0 +1,406 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+    static ref JSON_PALETTES: Vec<Palette<'static>> = Cmd::json_palette();
+    static ref VERBOSE_PALETTES: Vec<Palette<'static>> = Cmd::verbose_palette();
+    static ref LIST_BOOTS_PALETTES: Vec<Palette<'static>> = Cmd::list_boots_palette();
+    static ref DISK_USAGE_PALETTES: Vec<Palette<'static>> = Cmd::disk_usage_palette();
+    static ref FIELDS_PALETTES: Vec<Palette<'static>> = Cmd::fields_palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    pub fn json_palettes() -> &'static [Palette<'static>] {
+        &JSON_PALETTES
+    }
+
+    pub fn verbose_palettes() -> &'static [Palette<'static>] {
+        &VERBOSE_PALETTES
+    }
+
+    pub fn list_boots_palettes() -> &'static [Palette<'static>] {
+        &LIST_BOOTS_PALETTES
+    }
+
+    pub fn disk_usage_palettes() -> &'static [Palette<'static>] {
+        &DISK_USAGE_PALETTES
+    }
+
+    pub fn fields_palettes() -> &'static [Palette<'static>] {
+        &FIELDS_PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        let mut palettes = vec![
+            Palette {
+                name: "Connect requires special attention",
+                regexp: LazyRegex::new(r#"connect"#),
+                colors: vec![&Colors::OnRed],
+            },
+            Palette {
+                name: "Status deferred",
+                regexp: LazyRegex::new(r#"status\=deferred|Connection refused"#),
+                colors: vec![&Colors::Red],
+            },
+        ];
+        palettes.extend(Cmd::http_palette());
+        palettes.extend(vec![
+            Palette {
+                name: "Email address",
+                regexp: LazyRegex::new(r#"[a-zA-z0-9\.\-\+]+\@[\w\-\.]+"#),
+                colors: vec![&Colors::Green],
+            },
+            // -o short, short-precise, short-iso, short-iso-precise, short-full, short-monotonic
+            // and short-unix, e.g. "Jun 15 10:01:12", "2021-06-15T10:01:12+0800" or "[    5.123456]",
+            // also the RFC 3339 timestamps of rsyslog, e.g. "2021-06-15T10:01:12.123456+08:00"
+            Palette {
+                name: "Date and hostname",
+                regexp: LazyRegex::new(
+                    r#"^(... [\d ]\d \d\d:\d\d:\d\d(?:\.\d{6})?|\d{4}-\d\d-\d\dT\d\d:\d\d:\d\d(?:\.\d{6})?(?:[+-]\d\d:?\d\d|Z)|\w{3} \d{4}-\d\d-\d\d \d\d:\d\d:\d\d(?:\.\d{6})? \w+|\[\s*\d+\.\d{6}\]|\d+\.\d{6})(\s[-.\w\d]+?\s)"#,
+                ),
+                colors: vec![&Colors::Green, &Colors::Green, &Colors::Yellow],
+            },
+        ]);
+        palettes.extend(Cmd::address_palette());
+        palettes.extend(vec![
+            Palette {
+                name: "Name of process and pid",
+                regexp: LazyRegex::new(r#"([\w/\.\-]+)(\[\d+?\])"#),
+                colors: vec![&Colors::BBlue, &Colors::BRed],
+            },
+            Palette {
+                name: "Everything in <>",
+                regexp: LazyRegex::new(r#"\<.*?\>"#),
+                colors: vec![&Colors::Blue],
+            },
+        ]);
+        palettes.extend(Cmd::path_palette());
+        palettes.extend(vec![
+            Palette {
+                name: "Everything in \"",
+                regexp: LazyRegex::new(r#"\".*?\""#),
+                colors: vec![&Colors::Blue],
+            },
+            Palette {
+                name: "Everything in `'",
+                regexp: LazyRegex::new(r#"\`.+?\'"#),
+                colors: vec![&Colors::BYellow],
+            },
+            Palette {
+                name: "Everything in parentheses",
+                regexp: LazyRegex::new(r#"\(.*?\)"#),
+                colors: vec![&Colors::Blue],
+            },
+            Palette {
+                name: "Last message repeated",
+                // Display this line in yellow and stop further processing
+                regexp: LazyRegex::new(r#".*last message repeated \d+ times$"#),
+                colors: vec![&Colors::Yellow],
+            },
+        ]);
+        palettes
+    }
+
+    // The rules shared with the log files of `ufc log`, e.g. the access logs of nginx
+    pub fn http_palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "5xx status",
+                regexp: LazyRegex::new(r#"\s\b5\d{2}\b\s"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "4xx status",
+                regexp: LazyRegex::new(r#"\s\b4\d{2}\b\s"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "3xx status",
+                regexp: LazyRegex::new(r#"\s\b3\d{2}\b\s"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "2xx status",
+                regexp: LazyRegex::new(r#"\s\b2\d{2}\b\s"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "HTTP verbs",
+                regexp: LazyRegex::new(r#"GET|POST|PUT|DELETE|PATCH|HEAD"#),
+                colors: vec![&Colors::Green],
+            },
+        ]
+    }
+
+    pub fn address_palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "IPv6",
+                regexp: LazyRegex::new(r#"\b[0-9a-fA-F]{1,4}(\:\:?[0-9a-fA-F]{1,4})+"#),
+                colors: vec![&Colors::BYellow],
+            },
+            Palette {
+                name: "IPv4 or IPv4:Port",
+                regexp: LazyRegex::new(r#"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}(:\d{1,5})?"#),
+                colors: vec![&Colors::BYellow],
+            },
+        ]
+    }
+
+    pub fn path_palette() -> Vec<Palette<'static>> {
+        vec![Palette {
+            name: "This is probably a pathname",
+            regexp: LazyRegex::new(r#"\s/[a-zA-Z_/\.\-\?\d\=\&]+"#),
+            colors: vec![&Colors::Blue],
+        }]
+    }
+
+    // -o json is an object per line, -o json-pretty is indented like
+    //     "PRIORITY" : "6",
+    fn json_palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "PRIORITY emerg/alert/crit/err",
+                regexp: LazyRegex::new(r#"("PRIORITY")(\s*:\s*)("[0-3]")"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BRed,
+                ],
+            },
+            Palette {
+                name: "PRIORITY warning",
+                regexp: LazyRegex::new(r#"("PRIORITY")(\s*:\s*)("4")"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BYellow,
+                ],
+            },
+            Palette {
+                name: "PRIORITY debug",
+                regexp: LazyRegex::new(r#"("PRIORITY")(\s*:\s*)("7")"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BBlack,
+                ],
+            },
+            Palette {
+                name: "MESSAGE",
+                regexp: LazyRegex::new(r#"("MESSAGE")(\s*:\s*)("(?:[^"\\]|\\.)*")"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BWhite,
+                ],
+            },
+            // Address fields like __CURSOR or __REALTIME_TIMESTAMP
+            Palette {
+                name: "Address fields",
+                regexp: LazyRegex::new(r#"("__[A-Z_]+")(\s*:\s*)("(?:[^"\\]|\\.)*")"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::BBlack,
+                    &Colors::Default,
+                    &Colors::BBlack,
+                ],
+            },
+            Palette {
+                name: "JSON key",
+                regexp: LazyRegex::new(r#"("(?:[^"\\]|\\.)*")(\s*:)"#),
+                colors: vec![&Colors::Default, &Colors::Blue],
+            },
+            Palette {
+                name: "JSON string",
+                regexp: LazyRegex::new(r#""(?:[^"\\]|\\.)*""#),
+                colors: vec![&Colors::Green],
+            },
+            // Binary fields are arrays of bytes
+            Palette {
+                name: "JSON number",
+                regexp: LazyRegex::new(r#"(?<![\w.])-?\d+(?:\.\d+)?(?:[eE][+-]?\d+)?(?![\w.])"#),
+                colors: vec![&Colors::Cyan],
+            },
+            Palette {
+                name: "JSON null",
+                regexp: LazyRegex::new(r#"\bnull\b"#),
+                colors: vec![&Colors::BBlack],
+            },
+            Palette {
+                name: "JSON brackets",
+                regexp: LazyRegex::new(r#"[\[\]{}]"#),
+                colors: vec![&Colors::BWhite],
+            },
+        ]
+    }
+
+    // -o verbose prints the fields of an entry under its timestamp and cursor,
+    // -o export the same without the indentation
+    fn verbose_palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Timestamp and cursor",
+                regexp: LazyRegex::new(
+                    r#"^(\w{3} \d{4}-\d\d-\d\d \d\d:\d\d:\d\d(?:\.\d{6})? \w+)\s(\[.+\])$"#,
+                ),
+                colors: vec![&Colors::Default, &Colors::BGreen, &Colors::BBlack],
+            },
+            Palette {
+                name: "PRIORITY emerg/alert/crit/err",
+                regexp: LazyRegex::new(r#"^\s*(PRIORITY)(=)([0-3])$"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BRed,
+                ],
+            },
+            Palette {
+                name: "PRIORITY warning",
+                regexp: LazyRegex::new(r#"^\s*(PRIORITY)(=)(4)$"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BYellow,
+                ],
+            },
+            Palette {
+                name: "PRIORITY debug",
+                regexp: LazyRegex::new(r#"^\s*(PRIORITY)(=)(7)$"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BBlack,
+                ],
+            },
+            Palette {
+                name: "MESSAGE",
+                regexp: LazyRegex::new(r#"^\s*(MESSAGE)(=)(.*)$"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BWhite,
+                ],
+            },
+            // Address fields of -o export, e.g. "__CURSOR=s=..."
+            Palette {
+                name: "Address fields",
+                regexp: LazyRegex::new(r#"^__[A-Z0-9_]+=.*$"#),
+                colors: vec![&Colors::BBlack],
+            },
+            // Fields set by journald itself can't be faked by the clients, e.g. "_PID=1"
+            Palette {
+                name: "Trusted fields",
+                regexp: LazyRegex::new(r#"^\s*(_[A-Z0-9_]+)(=)"#),
+                colors: vec![&Colors::Default, &Colors::Cyan, &Colors::Default],
+            },
+            Palette {
+                name: "Fields",
+                regexp: LazyRegex::new(r#"^\s*([A-Z0-9_]+)(=)"#),
+                colors: vec![&Colors::Default, &Colors::Blue, &Colors::Default],
+            },
+        ]
+    }
+
+    // IDX BOOT ID                          FIRST ENTRY                 LAST ENTRY
+    //   0 0a1b2c3d4e5f60718293a4b5c6d7e8f9 Mon 2021-08-30 08:00:01 CST Mon 2021-08-30 23:59:59 CST
+    fn list_boots_palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "HEADERS",
+                regexp: LazyRegex::new(r#"(?:\s|^)(IDX|BOOT ID|FIRST ENTRY|LAST ENTRY)(?:\s|$)"#),
+                colors: vec![&Colors::Default, &Colors::UDefault],
+            },
+            Palette {
+                name: "Current boot",
+                regexp: LazyRegex::new(r#"^\s*0\s[\da-f]{32}\s"#),
+                colors: vec![&Colors::BGreen],
+            },
+            Palette {
+                name: "IDX",
+                regexp: LazyRegex::new(r#"^\s*-?\d+\s"#),
+                colors: vec![&Colors::Cyan],
+            },
+            Palette {
+                name: "BOOT ID",
+                regexp: LazyRegex::new(r#"^[\da-f]{32}\s"#),
+                colors: vec![&Colors::BBlack],
+            },
+            Palette {
+                name: "Entries",
+                regexp: LazyRegex::new(r#"\w{3} \d{4}-\d\d-\d\d \d\d:\d\d:\d\d \w+"#),
+                colors: vec![&Colors::Green],
+            },
+        ]
+    }
+
+    // Archived and active journals take up 1.2G in the file system.
+    fn disk_usage_palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Size 'T'",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+(?:\.\d+)?T(?=\s)"#),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "Size 'G'",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+(?:\.\d+)?G(?=\s)"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Size 'M'",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+(?:\.\d+)?M(?=\s)"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "Size 'K', 'B'",
+                regexp: LazyRegex::new(r#"(?<=\s)\d+(?:\.\d+)?[KB](?=\s)"#),
+                colors: vec![&Colors::Green],
+            },
+        ]
+    }
+
+    // --fields prints a field name per line
+    fn fields_palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Address fields",
+                regexp: LazyRegex::new(r#"^__[A-Z0-9_]+$"#),
+                colors: vec![&Colors::BBlack],
+            },
+            Palette {
+                name: "Trusted fields",
+                regexp: LazyRegex::new(r#"^_[A-Z0-9_]+$"#),
+                colors: vec![&Colors::Cyan],
+            },
+            Palette {
+                name: "MESSAGE and PRIORITY",
+                regexp: LazyRegex::new(r#"^(?:MESSAGE|PRIORITY)$"#),
+                colors: vec![&Colors::BWhite],
+            },
+            Palette {
+                name: "Fields",
+                regexp: LazyRegex::new(r#"^[A-Z0-9_]+$"#),
+                colors: vec![&Colors::Blue],
+            },
+        ]
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+    profiles::journalctl,
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref COMBINED_PALETTES: Vec<Palette<'static>> = Cmd::combined_palette();
+    static ref ERROR_PALETTES: Vec<Palette<'static>> = Cmd::error_palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn combined_palettes() -> &'static [Palette<'static>] {
+        &COMBINED_PALETTES
+    }
+
+    pub fn error_palettes() -> &'static [Palette<'static>] {
+        &ERROR_PALETTES
+    }
+
+    // 203.0.113.7 - frank [15/Jun/2021:10:01:12 +0800] "GET /index.html HTTP/1.1" 200 2326 "-" "curl/7.68.0"
+    fn combined_palette() -> Vec<Palette<'static>> {
+        let mut palettes = vec![
+            // Before the status so a size like 404 isn't taken for it
+            Palette {
+                name: "Response size",
+                regexp: LazyRegex::new(r#"(?<=" \d{3} )(?:\d+|-)(?=\s|$)"#),
+                colors: vec![&Colors::Cyan],
+            },
+            Palette {
+                name: "Referrer and user agent",
+                regexp: LazyRegex::new(r#""([^"]*)" "([^"]*)"$"#),
+                colors: vec![&Colors::Default, &Colors::DCyan, &Colors::DDefault],
+            },
+            // The remote address is left to the IPv4 and IPv6 rules
+            Palette {
+                name: "Identity and user",
+                regexp: LazyRegex::new(r#"^\S+ (\S+) (\S+) (?=\[)"#),
+                colors: vec![&Colors::Default, &Colors::DDefault, &Colors::Cyan],
+            },
+            Palette {
+                name: "Time",
+                regexp: LazyRegex::new(r#"\[\d\d/\w{3}/\d{4}:\d\d:\d\d:\d\d [+-]\d{4}\]"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Request path, query and protocol",
+                regexp: LazyRegex::new(r#""[A-Z]+ ([^\s?"]+)(\?[^\s"]*)? (HTTP/[\d.]+)""#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Cyan,
+                    &Colors::DDefault,
+                ],
+            },
+        ];
+        palettes.extend(journalctl::Cmd::http_palette());
+        palettes.extend(journalctl::Cmd::address_palette());
+        palettes
+    }
+
+    // [Tue Jun 15 10:01:12.123456 2021] [core:error] [pid 1234:tid 140] [client 203.0.113.7:51234] AH00126: ...
+    // 2021/06/15 10:01:12 [error] 1234#1234: *5 open() "/var/www/favicon.ico" failed (2: No such file or directory), client: ...
+    fn error_palette() -> Vec<Palette<'static>> {
+        let mut palettes = vec![
+            Palette {
+                name: "Date",
+                regexp: LazyRegex::new(
+                    r#"^\[\w{3} \w{3} [\d ]\d \d\d:\d\d:\d\d(?:\.\d+)? \d{4}\]|^\d{4}/\d\d/\d\d \d\d:\d\d:\d\d"#,
+                ),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Level emerg/alert/crit/error",
+                regexp: LazyRegex::new(r#"\[(?:\w+:)?(emerg|alert|crit|error)\]"#),
+                colors: vec![&Colors::Default, &Colors::BRed],
+            },
+            Palette {
+                name: "Level warn",
+                regexp: LazyRegex::new(r#"\[(?:\w+:)?(warn)\]"#),
+                colors: vec![&Colors::Default, &Colors::BYellow],
+            },
+            Palette {
+                name: "Level notice/info",
+                regexp: LazyRegex::new(r#"\[(?:\w+:)?(notice|info)\]"#),
+                colors: vec![&Colors::Default, &Colors::Green],
+            },
+            Palette {
+                name: "Level debug/trace",
+                regexp: LazyRegex::new(r#"\[(?:\w+:)?(debug|trace\d)\]"#),
+                colors: vec![&Colors::Default, &Colors::DDefault],
+            },
+            // "[pid 1234:tid 140]" of apache, "1234#1234: *5" of nginx
+            Palette {
+                name: "Process, thread and connection",
+                regexp: LazyRegex::new(r#"\[pid \d+(?::tid \d+)?\]|\b\d+#\d+: (?:\*\d+)?"#),
+                colors: vec![&Colors::DDefault],
+            },
+            Palette {
+                name: "Apache error code",
+                regexp: LazyRegex::new(r#"\bAH\d{5}\b"#),
+                colors: vec![&Colors::Magenta],
+            },
+            Palette {
+                name: "Error number",
+                regexp: LazyRegex::new(r#"\(\d+: [^)]*\)"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Fields of nginx",
+                regexp: LazyRegex::new(
+                    r#"\b(?:client|server|request|upstream|host|referrer|subrequest)(?=: )"#,
+                ),
+                colors: vec![&Colors::Cyan],
+            },
+        ];
+        palettes.extend(journalctl::Cmd::http_palette());
+        // Quoted urls, e.g. upstream: "http://127.0.0.1:8080/upload", are colored as a whole
+        palettes.push(Palette {
+            name: "Everything in \"",
+            regexp: LazyRegex::new(r#""(?:[^"\\]|\\.)*""#),
+            colors: vec![&Colors::Blue],
+        });
+        palettes.extend(journalctl::Cmd::address_palette());
+        palettes.extend(journalctl::Cmd::path_palette());
+        palettes
+    }
+}
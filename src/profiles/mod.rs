@@ -0,0 +1,214 @@
+mod arping;
+mod df;
+mod dig;
+mod docker;
+mod du;
+mod env;
+mod fdisk;
+mod findmnt;
+mod fping;
+mod free;
+mod id;
+mod ifconfig;
+mod journalctl;
+mod log;
+mod nping;
+mod ping;
+mod structured;
+mod tcping;
+mod top;
+mod universal;
+
+use crate::palette::Palette;
+
+// Every built-in palette set, the palettes are only built when a profile is looked up.
+// A subcommand ("docker ps") or an output mode ("journalctl json" for `journalctl -o json`,
+// "df inodes" for `df -i`)
+struct Profile {
+    name: &'static str,
+    palettes: fn() -> &'static [Palette<'static>],
+}
+
+const PROFILES: &[Profile] = &[
+    Profile {
+        name: "arping",
+        palettes: arping::Cmd::palettes,
+    },
+    Profile {
+        name: "df",
+        palettes: df::Cmd::palettes,
+    },
+    Profile {
+        name: "df inodes",
+        palettes: df::Cmd::inodes_palettes,
+    },
+    Profile {
+        name: "df output",
+        palettes: df::Cmd::output_palettes,
+    },
+    Profile {
+        name: "df type",
+        palettes: df::Cmd::type_palettes,
+    },
+    Profile {
+        name: "dig",
+        palettes: dig::Cmd::palettes,
+    },
+    Profile {
+        name: "docker build",
+        palettes: docker::build::Cmd::palettes,
+    },
+    Profile {
+        name: "docker compose",
+        palettes: docker::compose::Cmd::palettes,
+    },
+    Profile {
+        name: "docker events",
+        palettes: docker::events::Cmd::palettes,
+    },
+    Profile {
+        name: "docker images",
+        palettes: docker::images::Cmd::palettes,
+    },
+    Profile {
+        name: "docker info",
+        palettes: docker::info::Cmd::palettes,
+    },
+    Profile {
+        name: "docker inspect",
+        palettes: docker::inspect::Cmd::palettes,
+    },
+    Profile {
+        name: "docker logs",
+        palettes: docker::logs::Cmd::palettes,
+    },
+    Profile {
+        name: "docker network",
+        palettes: docker::network::Cmd::palettes,
+    },
+    Profile {
+        name: "docker ps",
+        palettes: docker::ps::Cmd::palettes,
+    },
+    Profile {
+        name: "docker stats",
+        palettes: docker::stats::Cmd::palettes,
+    },
+    Profile {
+        name: "docker system",
+        palettes: docker::system::Cmd::palettes,
+    },
+    Profile {
+        name: "docker version",
+        palettes: docker::version::Cmd::palettes,
+    },
+    Profile {
+        name: "docker volume",
+        palettes: docker::volume::Cmd::palettes,
+    },
+    Profile {
+        name: "du",
+        palettes: du::Cmd::palettes,
+    },
+    Profile {
+        name: "env",
+        palettes: env::Cmd::palettes,
+    },
+    Profile {
+        name: "fdisk",
+        palettes: fdisk::Cmd::palettes,
+    },
+    Profile {
+        name: "findmnt",
+        palettes: findmnt::Cmd::palettes,
+    },
+    Profile {
+        name: "fping",
+        palettes: fping::Cmd::palettes,
+    },
+    Profile {
+        name: "free",
+        palettes: free::Cmd::palettes,
+    },
+    Profile {
+        name: "free bytes",
+        palettes: free::Cmd::bytes_palettes,
+    },
+    Profile {
+        name: "id",
+        palettes: id::Cmd::palettes,
+    },
+    Profile {
+        name: "ifconfig",
+        palettes: ifconfig::Cmd::palettes,
+    },
+    Profile {
+        name: "journalctl",
+        palettes: journalctl::Cmd::palettes,
+    },
+    Profile {
+        name: "journalctl disk-usage",
+        palettes: journalctl::Cmd::disk_usage_palettes,
+    },
+    Profile {
+        name: "journalctl fields",
+        palettes: journalctl::Cmd::fields_palettes,
+    },
+    Profile {
+        name: "journalctl json",
+        palettes: journalctl::Cmd::json_palettes,
+    },
+    Profile {
+        name: "journalctl list-boots",
+        palettes: journalctl::Cmd::list_boots_palettes,
+    },
+    Profile {
+        name: "journalctl verbose",
+        palettes: journalctl::Cmd::verbose_palettes,
+    },
+    Profile {
+        name: "log combined",
+        palettes: log::Cmd::combined_palettes,
+    },
+    Profile {
+        name: "log error",
+        palettes: log::Cmd::error_palettes,
+    },
+    Profile {
+        name: "nping",
+        palettes: nping::Cmd::palettes,
+    },
+    Profile {
+        name: "ping",
+        palettes: ping::Cmd::palettes,
+    },
+    Profile {
+        name: "structured",
+        palettes: structured::Cmd::palettes,
+    },
+    Profile {
+        name: "tcping",
+        palettes: tcping::Cmd::palettes,
+    },
+    Profile {
+        name: "top",
+        palettes: top::Cmd::palettes,
+    },
+    Profile {
+        name: "universal",
+        palettes: universal::Cmd::palettes,
+    },
+];
+
+// The palettes of a built-in profile, e.g. "df" or "docker ps", see `ufc palette list`
+pub fn palettes(profile: &str) -> Option<&'static [Palette<'static>]> {
+    PROFILES
+        .iter()
+        .find(|candidate| candidate.name == profile)
+        .map(|profile| (profile.palettes)())
+}
+
+// The names of the built-in profiles, in the order `ufc palette list` prints them
+pub fn profiles() -> impl Iterator<Item = &'static str> {
+    PROFILES.iter().map(|profile| profile.name)
+}
@@ -0,0 +1,96 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    // One line per probe, e.g.
+    //
+    //     SENT (0.0120s) TCP 10.0.0.2:40125 > 1.1.1.1:80 S ttl=64 id=1 iplen=40  seq=1 win=1480
+    //     RCVD (0.0290s) TCP 1.1.1.1:80 > 10.0.0.2:40125 SA ttl=58 id=0 iplen=44  seq=2 win=64240 <mss 1460>
+    //
+    // then the statistics: "Max rtt: 17.2ms | Min rtt: 16.1ms | Avg rtt: 16.6ms" and the lost probes
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "SENT",
+                regexp: LazyRegex::new(r#"^(SENT) (\([0-9.]+s\))"#),
+                colors: vec![&Colors::Default, &Colors::BBlue, &Colors::DDefault],
+            },
+            Palette {
+                name: "RCVD",
+                regexp: LazyRegex::new(r#"^(RCVD) (\([0-9.]+s\))"#),
+                colors: vec![&Colors::Default, &Colors::BGreen, &Colors::DDefault],
+            },
+            Palette {
+                name: "Unreachable",
+                regexp: LazyRegex::new(r#"\bunreachable\b|\bFailed to resolve\b"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "TCP open",
+                regexp: LazyRegex::new(r#"(?<=\d) (SA)(?= ttl=)"#),
+                colors: vec![&Colors::Default, &Colors::Green],
+            },
+            Palette {
+                name: "TCP reset",
+                regexp: LazyRegex::new(r#"(?<=\d) (RA|R)(?= ttl=)"#),
+                colors: vec![&Colors::Default, &Colors::Red],
+            },
+            Palette {
+                name: "ICMP type",
+                regexp: LazyRegex::new(r#"\(type=\d+/code=\d+\)"#),
+                colors: vec![&Colors::DDefault],
+            },
+            // A gauge like the latency of ping
+            Palette {
+                name: "rtt",
+                regexp: LazyRegex::new(r#"(?<=rtt: )(?P<ms>[0-9.]+)ms"#),
+                colors: vec![&Colors::Green, &Colors::Yellow, &Colors::Red, &Colors::BRed],
+            },
+            Palette {
+                name: "No loss",
+                regexp: LazyRegex::new(r#"\bLost: 0 \(0\.00%\)"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Loss",
+                regexp: LazyRegex::new(r#"\b(?:Lost|Failed): [1-9]\d* \([0-9.]+%\)"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Statistics",
+                regexp: LazyRegex::new(
+                    r#"\b(?:Raw packets sent|Rcvd|TCP connection attempts|Successful connections|Failed): (\d+)"#,
+                ),
+                colors: vec![&Colors::Default, &Colors::BBlue],
+            },
+            Palette {
+                name: "Address:port",
+                regexp: LazyRegex::new(r#"\b(\d{1,3}(?:\.\d{1,3}){3}):(\d+)\b"#),
+                colors: vec![&Colors::Default, &Colors::BBlue, &Colors::Magenta],
+            },
+            Palette {
+                name: "IP",
+                regexp: LazyRegex::new(r#"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}"#),
+                colors: vec![&Colors::BBlue],
+            },
+            Palette {
+                name: "ttl=",
+                regexp: LazyRegex::new(r#"ttl=(\d+)"#),
+                colors: vec![&Colors::Default, &Colors::Magenta],
+            },
+        ]
+    }
+}
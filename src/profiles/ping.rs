@@ -0,0 +1,113 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        let mut palettes = Cmd::statistics_palette();
+        palettes.extend(vec![
+            Palette {
+                name: "unknown host",
+                regexp: LazyRegex::new(r#".+unknown\shost\s(.+)"#),
+                colors: vec![&Colors::Red, &Colors::BRed],
+            },
+            Palette {
+                name: "Errors",
+                regexp: LazyRegex::new(r#"(Destination Host Unreachable|100(\.0)?% packet loss)"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "OK",
+                regexp: LazyRegex::new(r#" 0(\.0)?% packet loss"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "DUP",
+                regexp: LazyRegex::new(r#"DUP\!"#),
+                colors: vec![&Colors::Red],
+            },
+            // A gauge, 100ms, 500ms and 1s unless --warn and --crit say otherwise
+            Palette {
+                name: "Latency",
+                regexp: LazyRegex::new(r#"(?<=time[=<])(?P<ms>[0-9.]+)\s?ms"#),
+                colors: vec![&Colors::Green, &Colors::Yellow, &Colors::Red, &Colors::BRed],
+            },
+            Palette {
+                name: "time",
+                regexp: LazyRegex::new(r#"([0-9\.]+)?\s?ms"#),
+                colors: vec![&Colors::Green, &Colors::BGreen],
+            },
+            Palette {
+                name: "name",
+                regexp: LazyRegex::new(r#"(?:[fF]rom|PING)\s(\S+)\s"#),
+                colors: vec![&Colors::Default, &Colors::Blue],
+            },
+            Palette {
+                name: "ttl=",
+                regexp: LazyRegex::new(r#"ttl=(\d+)"#),
+                colors: vec![&Colors::Default, &Colors::Magenta],
+            },
+            Palette {
+                name: "icmp_seq=",
+                regexp: LazyRegex::new(r#"icmp_seq=(\d+)"#),
+                colors: vec![&Colors::Default, &Colors::Magenta],
+            },
+            Palette {
+                name: "ipv6 number",
+                regexp: LazyRegex::new(r#"(([0-9a-fA-F]{1,4})?\:\:?[0-9a-fA-F]{1,4})+"#),
+                colors: vec![&Colors::Magenta],
+            },
+            Palette {
+                name: "IP",
+                regexp: LazyRegex::new(r#"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}"#),
+                colors: vec![&Colors::BBlue],
+            },
+        ]);
+        palettes
+    }
+
+    // The round-trip summary printed when ping ends, shared with arping
+    pub fn statistics_palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "last line values",
+                regexp: LazyRegex::new(r#"\=\s([0-9\.]+)/([0-9\.]+)/([0-9\.]+)/([0-9\.]+)"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::BYellow,
+                    &Colors::BBlue,
+                    &Colors::BRed,
+                    &Colors::BMagenta,
+                ],
+            },
+            Palette {
+                name: "last line min/avg/max/mdev",
+                regexp: LazyRegex::new(r#"rtt (min)/(avg)/(max)/(mdev|jitter|std-dev)"#),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::BYellow,
+                    &Colors::BBlue,
+                    &Colors::BRed,
+                    &Colors::BMagenta,
+                ],
+            },
+            Palette {
+                name: "statistics header",
+                regexp: LazyRegex::new(r#"--- (\S+) (?:ping )?statistics ---"#),
+                colors: vec![&Colors::BDefault, &Colors::BBlue],
+            },
+        ]
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+    profiles::journalctl,
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+// Keys and values of the levels, e.g. `level=error`, `"severity":"WARN"` or `"level":50` of pino
+const LEVEL_KEYS: &str = r#""?\b(?i:level|lvl|severity|loglevel|priority)"?"#;
+const ERROR_LEVELS: &str =
+    r#"(?i:emerg|emergency|alert|crit|critical|err|error|fatal|panic)|[0-3]|50|60"#;
+const WARNING_LEVELS: &str = r#"(?i:warn|warning)|4|40"#;
+const INFO_LEVELS: &str = r#"(?i:info|notice)|[56]|30"#;
+const DEBUG_LEVELS: &str = r#"(?i:debug|trace)|7|10|20"#;
+
+// Structured logs, a JSON object or `key=value` pairs (logfmt) per line:
+//
+//     {"level":"error","ts":1623722472.1,"msg":"failed to flush","attempt":3,"retry":true}
+//     level=error ts=2021-06-15T02:05:44Z msg="failed to flush" attempt=3 retry=true
+//
+// The level is looked for across the whole line and colors the message, then every key is split
+// from its value so the values are told apart by what they start with
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        let levels = [
+            ("error", ERROR_LEVELS, &Colors::Red, &Colors::BRed),
+            ("warning", WARNING_LEVELS, &Colors::Yellow, &Colors::BYellow),
+            ("info", INFO_LEVELS, &Colors::BDefault, &Colors::Green),
+            ("debug", DEBUG_LEVELS, &Colors::DDefault, &Colors::DDefault),
+        ];
+        let mut palettes: Vec<Palette<'static>> = levels
+            .iter()
+            .map(|(name, values, message, _)| Palette {
+                name: Box::leak(format!("Message at level {}", name).into_boxed_str()),
+                regexp: LazyRegex::from_string(format!(
+                    r#"^(?=.*{}\s*[=:]\s*"?(?:{})"?(?![\w.])).*?"?\b(?i:msg|message)"?\s*[=:]\s*("(?:[^"\\]|\\.)*"|[^\s",}}]+)"#,
+                    LEVEL_KEYS, values
+                )),
+                colors: vec![&Colors::Default, message],
+            })
+            .collect();
+        palettes.extend(levels.iter().map(|(name, values, _, level)| Palette {
+            name: Box::leak(format!("Level {}", name).into_boxed_str()),
+            regexp: LazyRegex::from_string(format!(
+                r#"({})(\s*[=:]\s*)("?(?:{})"?)(?![\w.])"#,
+                LEVEL_KEYS, values
+            )),
+            colors: vec![&Colors::Default, &Colors::Blue, &Colors::Default, level],
+        }));
+        palettes.extend(vec![
+            Palette {
+                name: "Message",
+                regexp: LazyRegex::new(
+                    r#"("?\b(?i:msg|message)"?)(\s*[=:]\s*)("(?:[^"\\]|\\.)*"|[^\s",}]+)"#,
+                ),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::BDefault,
+                ],
+            },
+            Palette {
+                name: "Time",
+                regexp: LazyRegex::new(
+                    r#"("?(?:\b(?i:time|ts|timestamp)|@timestamp)"?)(\s*[=:]\s*)("[^"]*"|[^\s",}]+)"#,
+                ),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::Green,
+                ],
+            },
+            Palette {
+                name: "Error",
+                regexp: LazyRegex::new(
+                    r#"("?\b(?i:err|error|exception|stacktrace)"?)(\s*[=:]\s*)("(?:[^"\\]|\\.)*")"#,
+                ),
+                colors: vec![
+                    &Colors::Default,
+                    &Colors::Blue,
+                    &Colors::Default,
+                    &Colors::Red,
+                ],
+            },
+            Palette {
+                name: "JSON key",
+                regexp: LazyRegex::new(r#"("(?:[^"\\]|\\.)*")(\s*:)"#),
+                colors: vec![&Colors::Default, &Colors::Blue],
+            },
+            // A JSON value right after its key or in an array, or a quoted logfmt value before
+            // the keys are split so "a=b" in a value isn't taken for a key
+            Palette {
+                name: "String",
+                regexp: LazyRegex::new(r#"(?:^[\s\[]*|(?<=[=,]))"(?:[^"\\]|\\.)*""#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Logfmt key",
+                regexp: LazyRegex::new(r#"(?:^|(?<=\s))([\w.\-/@]+)(=)"#),
+                colors: vec![&Colors::Default, &Colors::Blue, &Colors::Default],
+            },
+            Palette {
+                name: "Number",
+                regexp: LazyRegex::new(
+                    r#"^\s*-?\d+(?:\.\d+)?(?:[eE][+-]?\d+)?(?=[\s,\]}]|$)"#,
+                ),
+                colors: vec![&Colors::Cyan],
+            },
+            Palette {
+                name: "Boolean",
+                regexp: LazyRegex::new(r#"^\s*(?:true|false)(?=[\s,\]}]|$)"#),
+                colors: vec![&Colors::Magenta],
+            },
+            Palette {
+                name: "Null",
+                regexp: LazyRegex::new(r#"^\s*(?:null|nil|<nil>)(?=[\s,\]}]|$)"#),
+                colors: vec![&Colors::BBlack],
+            },
+        ]);
+        palettes.extend(journalctl::Cmd::address_palette());
+        palettes
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    // tcping of elifulkerson, e.g.
+    //
+    //     Probing 1.1.1.1:443/tcp - Port is open - time=12.345ms
+    //     Probing 1.1.1.1:443/tcp - No response - time=2001.234ms
+    //
+    // then "Minimum = 12.1ms, Maximum = 13.0ms, Average = 12.5ms"
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Open",
+                regexp: LazyRegex::new(r#"\bPort is open\b|\bis open\b|\bopen\.$"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Closed",
+                regexp: LazyRegex::new(r#"\bNo response\b|\bclosed\b|\btimeout\b|\bFailed\b"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Probing",
+                regexp: LazyRegex::new(r#"^Probing (\S+?):(\d+)/tcp"#),
+                colors: vec![&Colors::Default, &Colors::Blue, &Colors::Magenta],
+            },
+            Palette {
+                name: "No failure",
+                regexp: LazyRegex::new(r#"\(0\.00% fail\)"#),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "Failures",
+                regexp: LazyRegex::new(r#"\([0-9.]+% fail\)"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Minimum/Maximum/Average",
+                regexp: LazyRegex::new(
+                    r#"(?:(?<=Minimum = )|(?<=Maximum = )|(?<=Average = ))(?P<ms>[0-9.]+)ms"#,
+                ),
+                colors: vec![&Colors::Green, &Colors::Yellow, &Colors::Red, &Colors::BRed],
+            },
+            Palette {
+                name: "Address:port",
+                regexp: LazyRegex::new(r#"\b(\d{1,3}(?:\.\d{1,3}){3}):(\d+)\b"#),
+                colors: vec![&Colors::Default, &Colors::BBlue, &Colors::Magenta],
+            },
+            // A gauge like the latency of ping
+            Palette {
+                name: "Latency",
+                regexp: LazyRegex::new(r#"(?<=time=)(?P<ms>[0-9.]+)ms"#),
+                colors: vec![&Colors::Green, &Colors::Yellow, &Colors::Red, &Colors::BRed],
+            },
+        ]
+    }
+}
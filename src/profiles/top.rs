@@ -0,0 +1,62 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Header",
+                regexp: LazyRegex::new(r#"\s+PID.+COMMAND.+$"#),
+                colors: vec![&Colors::BlackOnGreen],
+            },
+            Palette {
+                name: "Swap",
+                regexp: LazyRegex::new(r#"Swap"#),
+                colors: vec![&Colors::BMagenta],
+            },
+            Palette {
+                name: "Mem",
+                regexp: LazyRegex::new(r#"Mem"#),
+                colors: vec![&Colors::BCyan],
+            },
+            Palette {
+                name: "Time",
+                regexp: LazyRegex::new(r#"\d+:\d+[:\.]\d+"#),
+                colors: vec![&Colors::BBlue],
+            },
+            Palette {
+                name: "Size 'T'",
+                regexp: LazyRegex::new(r#"\s\d*[.,]?\dt|\b\d{10,12}\b"#),
+                colors: vec![&Colors::BRed],
+            },
+            Palette {
+                name: "Size 'G'",
+                regexp: LazyRegex::new(r#"\s\d*[.,]?\dg|\b\d{7,9}\b"#),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Size 'M'",
+                regexp: LazyRegex::new(r#"\s\d*[.,]?\dm|\b\d{4,6}\b"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "Size 'K'",
+                regexp: LazyRegex::new(r#"\s\d*[.,]?\dk?"#),
+                colors: vec![&Colors::Green],
+            },
+        ]
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    colors::Colors,
+    palette::{LazyRegex, Palette},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref PALETTES: Vec<Palette<'static>> = Cmd::palette();
+}
+
+pub struct Cmd {}
+
+impl Cmd {
+    pub fn palettes() -> &'static [Palette<'static>] {
+        &PALETTES
+    }
+
+    fn palette() -> Vec<Palette<'static>> {
+        vec![
+            Palette {
+                name: "Warning",
+                regexp: LazyRegex::new(r#"[Ww]arning|[Aa]lert"#),
+                colors: vec![&Colors::Yellow],
+            },
+            Palette {
+                name: "Negative",
+                regexp: LazyRegex::new(
+                    r#"[Dd]isabled?|[Ee]rrors?|[Ss]topped|[Ff]alse|[Nn]one|[Tt]erminated|[Ff]aile?d?"#,
+                ),
+                colors: vec![&Colors::Red],
+            },
+            Palette {
+                name: "Positive",
+                regexp: LazyRegex::new(
+                    r#"[Ee]nabled?|[Oo]k|[Rr]unning|[Tt]rue|[Rr]eady|[Aa]ctive|[Aa]vailable|[Aa]pproved|[Cc]reated|[Cc]ompleted"#,
+                ),
+                colors: vec![&Colors::Green],
+            },
+            Palette {
+                name: "ipv6",
+                regexp: LazyRegex::new(r#"\b[0-9a-fA-F]{1,4}(\:\:?[0-9a-fA-F]{1,4})+"#),
+                colors: vec![&Colors::BCyan],
+            },
+            Palette {
+                name: "ipv4",
+                regexp: LazyRegex::new(r#"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}"#),
+                colors: vec![&Colors::Cyan],
+            },
+            // Gauges, see `gauge`: 70%, 90% and 98%, then 1M, 1G and 1T where a bare number
+            // counts KiB like most tools which print sizes
+            Palette {
+                name: "Percentage",
+                regexp: LazyRegex::new(r#"(?P<percent>\d{1,3}(?:[.,]\d+)?)%"#),
+                colors: vec![&Colors::Green, &Colors::Yellow, &Colors::Red, &Colors::BRed],
+            },
+            Palette {
+                name: "Size",
+                regexp: LazyRegex::new(r#"(?P<kib>(?<=\s)\d*[.,]?\d[KMGT]i?|\b\d{1,12}\b)"#),
+                colors: vec![&Colors::Green, &Colors::Yellow, &Colors::Red, &Colors::BRed],
+            },
+            Palette {
+                name: "Numbers",
+                regexp: LazyRegex::new(r#"\d*\.?\d+"#),
+                colors: vec![&Colors::BBlue],
+            },
+        ]
+    }
+}